@@ -140,6 +140,12 @@ impl<'a> JSXMemberExpression<'a> {
     }
 }
 
+impl<'a> std::fmt::Display for JSXMemberExpression<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.object, self.property.name)
+    }
+}
+
 #[visited_node]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
@@ -149,6 +155,15 @@ pub enum JSXMemberExpressionObject<'a> {
     MemberExpression(Box<'a, JSXMemberExpression<'a>>),
 }
 
+impl<'a> std::fmt::Display for JSXMemberExpressionObject<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Identifier(ident) => write!(f, "{}", ident.name),
+            Self::MemberExpression(expr) => expr.fmt(f),
+        }
+    }
+}
+
 #[visited_node]
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
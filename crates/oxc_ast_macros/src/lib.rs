@@ -2,6 +2,9 @@ use proc_macro::TokenStream;
 
 /// Attach to AST node type (struct or enum), to signal to codegen to create visitor for this type.
 /// Macro itself does nothing - just passes through the token stream unchanged.
+///
+/// `oxc_traverse`'s build script finds the types with this attribute in `oxc_ast/src/ast`,
+/// and generates their `walk_*` functions and `enter_*` / `exit_*` methods of `Traverse`.
 #[proc_macro_attribute]
 pub fn visited_node(_args: TokenStream, input: TokenStream) -> TokenStream {
     input
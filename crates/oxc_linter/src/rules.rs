@@ -214,6 +214,7 @@ mod react {
 }
 
 mod react_perf {
+    pub mod jsx_no_inline_props_in_loop;
     pub mod jsx_no_jsx_as_prop;
     pub mod jsx_no_new_array_as_prop;
     pub mod jsx_no_new_function_as_prop;
@@ -662,6 +663,7 @@ oxc_macros::declare_all_lint_rules! {
     react::require_render_return,
     react::rules_of_hooks,
     react::void_dom_elements_no_children,
+    react_perf::jsx_no_inline_props_in_loop,
     react_perf::jsx_no_jsx_as_prop,
     react_perf::jsx_no_new_array_as_prop,
     react_perf::jsx_no_new_function_as_prop,
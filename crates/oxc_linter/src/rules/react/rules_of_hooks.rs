@@ -1,16 +1,23 @@
 use itertools::{FoldWhile, Itertools};
 use oxc_ast::{
-    ast::{ArrowFunctionExpression, Function},
+    ast::{
+        ArrowFunctionExpression, AssignmentTarget, AssignmentTargetProperty, CallExpression,
+        Expression, Function, MemberExpression, VariableDeclarationKind, VariableDeclarator,
+    },
     AstKind,
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{
-    petgraph::{self},
+    petgraph::{
+        self,
+        visit::{Bfs, EdgeFiltered},
+    },
     pg::neighbors_filtered_by_edge_weight,
     AstNodeId, AstNodes, BasicBlockElement, BasicBlockId, EdgeType, Register,
 };
-use oxc_span::{Atom, CompactStr};
+use oxc_span::{Atom, CompactStr, GetSpan, Span};
 use oxc_syntax::operator::AssignmentOperator;
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
@@ -20,7 +27,7 @@ use crate::{
 };
 
 mod diagnostics {
-    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
     use oxc_span::Span;
 
     pub(super) fn function_error(span: Span, hook_name: &str, func_name: &str) -> OxcDiagnostic {
@@ -34,13 +41,26 @@ mod diagnostics {
         .with_label(span)
     }
 
-    pub(super) fn conditional_hook(span: Span, hook_name: &str) -> OxcDiagnostic {
-        OxcDiagnostic::warn(format!(
+    pub(super) fn conditional_hook(
+        span: Span,
+        hook_name: &str,
+        branch_span: Option<Span>,
+    ) -> OxcDiagnostic {
+        let diagnostic = OxcDiagnostic::warn(format!(
             "eslint-plugin-react-hooks(rules-of-hooks): \
             React Hook {hook_name:?} is called conditionally. React Hooks must be \
             called in the exact same order in every component render."
-        ))
-        .with_label(span)
+        ));
+        match branch_span {
+            Some(branch_span) => diagnostic.with_labels([
+                span.into(),
+                LabeledSpan::new_with_span(
+                    Some("Whether the hook is called depends on this condition".into()),
+                    branch_span,
+                ),
+            ]),
+            None => diagnostic.with_label(span),
+        }
     }
 
     pub(super) fn loop_hook(span: Span, hook_name: &str) -> OxcDiagnostic {
@@ -71,6 +91,16 @@ mod diagnostics {
         .with_label(span)
     }
 
+    pub(super) fn generator_component(span: Span, hook_name: &str) -> OxcDiagnostic {
+        OxcDiagnostic::warn(format!(
+            "eslint-plugin-react-hooks(rules-of-hooks): \
+            React Hook {hook_name:?} cannot be called in a generator function. React Hooks \
+            must be called in a React function component or a custom React \
+            Hook function."
+        ))
+        .with_label(span)
+    }
+
     pub(super) fn class_component(span: Span, hook_name: &str) -> OxcDiagnostic {
         OxcDiagnostic::warn(format!(
             "eslint-plugin-react-hooks(rules-of-hooks): \
@@ -93,7 +123,42 @@ mod diagnostics {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct RulesOfHooks;
+pub struct RulesOfHooks(Box<RulesOfHooksConfig>);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RulesOfHooksConfig {
+    /// Names of higher order components whose callbacks are treated as component bodies,
+    /// e.g. `observer` from `mobx-react`. Defaults to `memo` and `forwardRef`.
+    #[serde(default = "default_component_wrappers")]
+    component_wrappers: Vec<String>,
+    /// Names of functions which look like hooks but aren't, e.g. `useFakeTimers` from Jest,
+    /// which are never reported.
+    #[serde(default)]
+    ignore_hooks: Vec<String>,
+    /// Number of basic blocks in a function's control flow graph above which its hooks are only
+    /// checked for the branches around them in the AST, to bound the time spent on huge functions.
+    #[serde(default = "default_max_cfg_blocks")]
+    max_cfg_blocks: usize,
+}
+
+impl Default for RulesOfHooksConfig {
+    fn default() -> Self {
+        Self {
+            component_wrappers: default_component_wrappers(),
+            ignore_hooks: vec![],
+            max_cfg_blocks: default_max_cfg_blocks(),
+        }
+    }
+}
+
+fn default_component_wrappers() -> Vec<String> {
+    vec!["memo".to_string(), "forwardRef".to_string()]
+}
+
+fn default_max_cfg_blocks() -> usize {
+    10_000
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -102,25 +167,72 @@ declare_oxc_lint!(
     ///
     /// <https://reactjs.org/docs/hooks-rules.html>
     ///
+    /// ### Options
+    ///
+    /// `componentWrappers` lists the higher order components whose callbacks are
+    /// treated as components, defaulting to `["memo", "forwardRef"]`:
+    ///
+    /// ```json
+    /// { "react/rules-of-hooks": ["error", { "componentWrappers": ["memo", "forwardRef", "observer"] }] }
+    /// ```
+    ///
+    /// `ignoreHooks` lists the names of functions which start with `use` but are not hooks,
+    /// so calls to them are never reported, e.g. `jest.useFakeTimers()`:
+    ///
+    /// ```json
+    /// { "react/rules-of-hooks": ["error", { "ignoreHooks": ["useFakeTimers"] }] }
+    /// ```
+    ///
+    /// `maxCfgBlocks` is the number of basic blocks in a function's control flow graph above which
+    /// the rule only looks for `if`s, ternaries and logical expressions around hooks, and early
+    /// exits in them, instead of analysing every path through the function, defaulting to `10000`:
+    ///
+    /// ```json
+    /// { "react/rules-of-hooks": ["error", { "maxCfgBlocks": 50000 }] }
+    /// ```
+    ///
+    /// If the options can't be parsed, e.g. `"maxCfgBlocks": "50000"`, all of them are ignored
+    /// and the defaults are used instead.
+    ///
     RulesOfHooks,
     nursery
 );
 
 impl Rule for RulesOfHooks {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        value
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .map_or_else(Self::default, |value| Self(Box::new(value)))
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call) = node.kind() else { return };
 
-        if !is_react_hook(&call.callee) {
+        let (hook_name, is_use) = if is_react_hook(&call.callee) {
+            let hook_name = call
+                .callee_name()
+                .expect("We identify hooks using their names so it should be named.");
+            (hook_name, is_react_function_call(call, "use"))
+        } else if let Some(hook_name) = resolve_hook_alias(&call.callee, ctx) {
+            (hook_name, hook_name == "use")
+        } else {
+            return;
+        };
+        if self.0.ignore_hooks.iter().any(|name| name == hook_name) {
             return;
         }
         let span = call.span;
-        let hook_name =
-            call.callee_name().expect("We identify hooks using their names so it should be named.");
 
         let semantic = ctx.semantic();
         let nodes = semantic.nodes();
 
-        let is_use = is_react_function_call(call, "use");
+        // Hooks called directly in a class static block run during class initialization,
+        // so they are reported as class hooks rather than top level hooks.
+        if is_directly_inside_static_block(nodes, node) {
+            return ctx.diagnostic(diagnostics::class_component(span, hook_name));
+        }
 
         let Some(parent_func) = parent_func(nodes, node) else {
             return ctx.diagnostic(diagnostics::top_level_hook(span, hook_name));
@@ -139,6 +251,11 @@ impl Rule for RulesOfHooks {
         }
 
         match parent_func.kind() {
+            // Hooks can't be called from generator functions, even ones named like components.
+            AstKind::Function(Function { id, span, generator: true, .. }) => {
+                let span = id.as_ref().map_or(*span, |id| id.span);
+                return ctx.diagnostic(diagnostics::generator_component(span, hook_name));
+            }
             // We are in a named function that isn't a hook or component, which is illegal
             AstKind::Function(Function { id: Some(id), .. })
                 if !is_react_component_or_hook_name(&id.name) =>
@@ -151,11 +268,21 @@ impl Rule for RulesOfHooks {
             }
             // Hooks are allowed inside of unnamed functions used as arguments. As long as they are
             // not used as a callback inside of components or hooks.
+            // The same goes for functions used as JSX attribute values, e.g. event handlers,
+            // and for immediately invoked functions, e.g. in JSX children.
             AstKind::Function(Function { id: None, .. }) | AstKind::ArrowFunctionExpression(_)
-                if is_non_react_func_arg(nodes, parent_func.id()) =>
+                if is_non_react_func_arg(nodes, parent_func.id(), &self.0.component_wrappers)
+                    || is_jsx_attribute_value(nodes, parent_func.id())
+                    || is_immediately_invoked(nodes, parent_func.id()) =>
             {
                 // This rule doesn't apply to `use(...)`.
-                if !is_use && is_somewhere_inside_component_or_hook(nodes, parent_func.id()) {
+                if !is_use
+                    && is_somewhere_inside_component_or_hook(
+                        nodes,
+                        parent_func.id(),
+                        &self.0.component_wrappers,
+                    )
+                {
                     ctx.diagnostic(diagnostics::generic_error(span, hook_name));
                 }
                 return;
@@ -188,13 +315,16 @@ impl Rule for RulesOfHooks {
                 //         useState(0);
                 //     }
                 // }
-                if ident.is_some_and(|name| !is_react_component_or_hook_name(name.as_str()))
+                if ident
+                    .as_ref()
+                    .is_some_and(|name| !is_react_component_or_hook_name(name.as_str()))
                     || is_export_default(nodes, parent_func.id())
                 {
+                    // Named after the binding like upstream, e.g. `handler` for `{ handler() {} }`
                     return ctx.diagnostic(diagnostics::function_error(
                         *span,
                         hook_name,
-                        "Anonymous",
+                        ident.as_deref().unwrap_or("Anonymous"),
                     ));
                 }
             }
@@ -220,6 +350,18 @@ impl Rule for RulesOfHooks {
             return;
         }
 
+        // Default values of parameters and destructured bindings are only evaluated if
+        // the value is `undefined`, e.g. `function Component(x = useState()) {}`.
+        if is_in_default_value(nodes, node, parent_func) {
+            return ctx.diagnostic(diagnostics::conditional_hook(span, hook_name, None));
+        }
+
+        // Optional chaining skips the call if anything before it in the chain is nullish,
+        // which the control flow graph doesn't model.
+        if is_short_circuited_call(call) || is_in_short_circuited_chain(nodes, node, parent_func) {
+            return ctx.diagnostic(diagnostics::conditional_hook(span, hook_name, None));
+        }
+
         let node_cfg_id = node.cfg_id();
         let func_cfg_id = parent_func.cfg_id();
 
@@ -246,11 +388,23 @@ impl Rule for RulesOfHooks {
             return ctx.diagnostic(diagnostics::loop_hook(span, hook_name));
         }
 
+        // The control flow analysis below is quadratic in the size of the graph, so in huge
+        // functions the hook is only reported if it's in a branch, or after an early exit in one.
+        if self.exceeds_max_cfg_blocks(ctx, func_cfg_id) {
+            if let Some(branch_span) = conditional_branch_span(nodes, node, parent_func) {
+                let diagnostic = diagnostics::conditional_hook(span, hook_name, Some(branch_span));
+                ctx.diagnostic(diagnostic);
+            }
+            return;
+        }
+
         if self.is_conditional(ctx, func_cfg_id, node_cfg_id)
-            || self.breaks_early(ctx, func_cfg_id, node_cfg_id)
+            || (self.breaks_early(ctx, func_cfg_id, node_cfg_id)
+                && is_skipped_by_break(nodes, node, parent_func))
         {
+            let branch_span = conditional_branch_span(nodes, node, parent_func);
             #[allow(clippy::needless_return)]
-            return ctx.diagnostic(diagnostics::conditional_hook(span, hook_name));
+            return ctx.diagnostic(diagnostics::conditional_hook(span, hook_name, branch_span));
         }
     }
 }
@@ -274,6 +428,16 @@ impl RulesOfHooks {
         })
     }
 
+    /// Checks if the function starting at `func_cfg_id` has more than `maxCfgBlocks` basic
+    /// blocks, not counting the blocks of nested functions.
+    fn exceeds_max_cfg_blocks(&self, ctx: &LintContext, func_cfg_id: BasicBlockId) -> bool {
+        let graph = EdgeFiltered::from_fn(&ctx.semantic().cfg().graph, |e| {
+            !matches!(e.weight(), EdgeType::NewFunction)
+        });
+        let mut bfs = Bfs::new(&graph, func_cfg_id);
+        std::iter::from_fn(|| bfs.next(&graph)).nth(self.0.max_cfg_blocks).is_some()
+    }
+
     #[inline(always)]
     fn is_conditional(
         &self,
@@ -346,11 +510,212 @@ fn parent_func<'a>(nodes: &'a AstNodes<'a>, node: &AstNode) -> Option<&'a AstNod
     nodes.ancestors(node.id()).map(|id| nodes.get_node(id)).find(|it| it.kind().is_function_like())
 }
 
+/// Checks if `call` is skipped when part of its optional chain is nullish,
+/// e.g. `useHook?.()`, `React?.useState()` or `a?.B.useState()`.
+fn is_short_circuited_call(call: &CallExpression) -> bool {
+    call.optional || is_short_circuited(&call.callee)
+}
+
+/// Checks if the calls and member accesses after `expr` in its optional chain are skipped
+/// when part of `expr` is nullish, e.g. `a?.b` in `a?.b.c()`.
+fn is_short_circuited(expr: &Expression) -> bool {
+    let mut expr = expr;
+    loop {
+        match expr {
+            Expression::CallExpression(call) if call.optional => return true,
+            Expression::CallExpression(call) => expr = &call.callee,
+            _ => match expr.as_member_expression() {
+                Some(member) if member.optional() => return true,
+                Some(member) => expr = member.object(),
+                None => return false,
+            },
+        }
+    }
+}
+
+/// Checks if `node` is inside the arguments or computed property of a call or member access in
+/// `func` which is skipped by optional chaining, e.g. `maybe?.(useHook())` or `a?.[useHook()]`.
+fn is_in_short_circuited_chain(nodes: &AstNodes, node: &AstNode, func: &AstNode) -> bool {
+    let span = node.kind().span();
+    nodes.ancestors(node.id()).take_while(|id| *id != func.id()).any(|id| match nodes.kind(id) {
+        AstKind::CallExpression(call) => {
+            call.arguments.iter().any(|argument| span_contains(argument.span(), span))
+                && is_short_circuited_call(call)
+        }
+        AstKind::MemberExpression(MemberExpression::ComputedMemberExpression(member)) => {
+            span_contains(member.expression.span(), span)
+                && (member.optional || is_short_circuited(&member.object))
+        }
+        _ => false,
+    })
+}
+
+/// Checks if `outer` contains `inner`.
+fn span_contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// Checks if `node` is inside the default value of a binding or assignment target in `func`,
+/// e.g. `x = useHook()` in a parameter list, `{ x = useHook() }` in a destructuring,
+/// or `[x = useHook()] = arr` in a destructuring assignment.
+fn is_in_default_value(nodes: &AstNodes, node: &AstNode, func: &AstNode) -> bool {
+    let span = node.kind().span();
+    let contains = |init: &Expression| span_contains(init.span(), span);
+    nodes.ancestors(node.id()).take_while(|id| *id != func.id()).any(|id| match nodes.kind(id) {
+        AstKind::AssignmentPattern(_) => true,
+        AstKind::AssignmentTargetWithDefault(target) => contains(&target.init),
+        // `{ x = useHook() } = props`, where the property has no node of its own
+        AstKind::AssignmentTarget(AssignmentTarget::ObjectAssignmentTarget(target)) => {
+            target.properties.iter().any(|property| match property {
+                AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                    ident.init.as_ref().is_some_and(contains)
+                }
+                AssignmentTargetProperty::AssignmentTargetPropertyProperty(_) => false,
+            })
+        }
+        _ => false,
+    })
+}
+
+/// Checks if a `break` before `node` in the same function exits a statement which contains `node`,
+/// e.g. `label: { if (a) break label; useHook(); }`.
+///
+/// Breaking out of a loop or `switch` which comes before `node` doesn't skip it.
+/// The control flow graph keeps a fall-through edge after `break`, so it can't tell these apart.
+fn is_skipped_by_break(nodes: &AstNodes, node: &AstNode, func: &AstNode) -> bool {
+    nodes_before(nodes, node, func).any(|it| breaks_out_of_ancestor(nodes, it, node))
+}
+
+/// Checks if `it` is a `break` out of a statement which contains `node`.
+fn breaks_out_of_ancestor(nodes: &AstNodes, it: &AstNode, node: &AstNode) -> bool {
+    let AstKind::BreakStatement(stmt) = it.kind() else { return false };
+    let target = nodes.ancestors(it.id()).find(|id| match nodes.kind(*id) {
+        AstKind::LabeledStatement(labeled) => {
+            stmt.label.as_ref().is_some_and(|label| label.name == labeled.label.name)
+        }
+        AstKind::DoWhileStatement(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::ForOfStatement(_)
+        | AstKind::ForStatement(_)
+        | AstKind::WhileStatement(_)
+        | AstKind::SwitchStatement(_) => stmt.label.is_none(),
+        _ => false,
+    });
+    target.is_some_and(|target| nodes.ancestors(node.id()).any(|id| id == target))
+}
+
+/// Iterate over the nodes of `func` which end before `node` starts, excluding nested functions.
+///
+/// Nodes are numbered in the order they are visited, so only the nodes numbered between
+/// `func` and `node` are looked at rather than every node in the file.
+fn nodes_before<'b, 'a>(
+    nodes: &'b AstNodes<'a>,
+    node: &AstNode,
+    func: &AstNode,
+) -> impl Iterator<Item = &'b AstNode<'a>> + 'b {
+    let node_start = node.kind().span().start;
+    let func_id = func.id();
+    (func_id.index() + 1..node.id().index()).map(|id| nodes.get_node(AstNodeId::new(id))).filter(
+        move |it| {
+            it.kind().span().end <= node_start
+                && parent_func(nodes, it).map(AstNode::id) == Some(func_id)
+        },
+    )
+}
+
+/// Resolve a hook called through a local alias, e.g. `f` in `const f = useState; f();`.
+///
+/// Returns the name of the aliased hook. This is best-effort: only a `const` declared
+/// directly as a hook is resolved, not aliases of aliases, reassignments or destructuring.
+fn resolve_hook_alias<'a>(callee: &Expression<'a>, ctx: &LintContext<'a>) -> Option<&'a str> {
+    let Expression::Identifier(ident) = callee else { return None };
+    let symbols = ctx.symbols();
+    let symbol_id = symbols.get_reference(ident.reference_id.get()?).symbol_id()?;
+    let AstKind::VariableDeclarator(VariableDeclarator {
+        kind: VariableDeclarationKind::Const,
+        init: Some(init),
+        ..
+    }) = ctx.nodes().kind(symbols.get_declaration(symbol_id))
+    else {
+        return None;
+    };
+    if !is_react_hook(init) {
+        return None;
+    }
+    match init {
+        Expression::Identifier(ident) => Some(ident.name.as_str()),
+        init => init.as_member_expression().and_then(MemberExpression::static_property_name),
+    }
+}
+
+/// Find the condition which decides whether the hook `node` in `func` is called, if any.
+///
+/// This is the test of the nearest `if`, ternary, or logical expression around the hook,
+/// or of the nearest `if` around an earlier `return` or `break` which skips it.
+fn conditional_branch_span(nodes: &AstNodes, node: &AstNode, func: &AstNode) -> Option<Span> {
+    let span = node.kind().span();
+    let enclosing_branch = nodes
+        .ancestors(node.id())
+        .take_while(|id| *id != func.id())
+        .find_map(|id| branch_test_span(nodes.kind(id), span));
+    if enclosing_branch.is_some() {
+        return enclosing_branch;
+    }
+
+    // if (a) return;
+    // useHook();
+    nodes_before(nodes, node, func)
+        .filter(|it| {
+            matches!(it.kind(), AstKind::ReturnStatement(_))
+                || breaks_out_of_ancestor(nodes, it, node)
+        })
+        .find_map(|it| {
+            let exit_span = it.kind().span();
+            nodes
+                .ancestors(it.id())
+                .take_while(|id| *id != func.id())
+                .map(|id| nodes.kind(id))
+                .filter(|kind| !contains(kind.span(), span))
+                .find_map(|kind| branch_test_span(kind, exit_span))
+        })
+}
+
+/// Span of the test of `kind`, if `kind` is a branch and `span` is in one of its conditional arms.
+fn branch_test_span(kind: AstKind, span: Span) -> Option<Span> {
+    match kind {
+        AstKind::IfStatement(stmt) if !contains(stmt.test.span(), span) => Some(stmt.test.span()),
+        AstKind::ConditionalExpression(expr) if !contains(expr.test.span(), span) => {
+            Some(expr.test.span())
+        }
+        AstKind::LogicalExpression(expr) if contains(expr.right.span(), span) => {
+            Some(expr.left.span())
+        }
+        _ => None,
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// Checks if the nearest enclosing function-like scope of `node` is a class `static` block.
+fn is_directly_inside_static_block(nodes: &AstNodes, node: &AstNode) -> bool {
+    nodes
+        .ancestors(node.id())
+        .map(|id| nodes.kind(id))
+        .find(|kind| kind.is_function_like() || matches!(kind, AstKind::StaticBlock(_)))
+        .is_some_and(|kind| matches!(kind, AstKind::StaticBlock(_)))
+}
+
 /// Checks if the `node_id` is a callback argument,
 /// And that function isn't a `React.memo` or `React.forwardRef`.
 /// Returns `true` if this node is a function argument and that isn't a React special function.
 /// Otherwise it would return `false`.
-fn is_non_react_func_arg(nodes: &AstNodes, node_id: AstNodeId) -> bool {
+fn is_non_react_func_arg(
+    nodes: &AstNodes,
+    node_id: AstNodeId,
+    component_wrappers: &[String],
+) -> bool {
     let argument = match nodes.parent_node(node_id) {
         Some(parent) if matches!(parent.kind(), AstKind::Argument(_)) => parent,
         _ => return false,
@@ -360,10 +725,34 @@ fn is_non_react_func_arg(nodes: &AstNodes, node_id: AstNodeId) -> bool {
         return false;
     };
 
-    !(is_react_function_call(call, "forwardRef") || is_react_function_call(call, "memo"))
+    !component_wrappers.iter().any(|wrapper| is_react_function_call(call, wrapper.as_str()))
+}
+
+/// `<button onClick={() => {}} />`
+fn is_jsx_attribute_value(nodes: &AstNodes, node_id: AstNodeId) -> bool {
+    let Some(container) = nodes.parent_node(node_id) else { return false };
+    matches!(container.kind(), AstKind::JSXExpressionContainer(_))
+        && matches!(nodes.parent_kind(container.id()), Some(AstKind::JSXAttributeItem(_)))
+}
+
+/// `(() => {})()`
+fn is_immediately_invoked(nodes: &AstNodes, node_id: AstNodeId) -> bool {
+    let span = nodes.kind(node_id).span();
+    nodes
+        .ancestors(node_id)
+        .skip(1)
+        .map(|id| nodes.kind(id))
+        .find(|kind| !matches!(kind, AstKind::ParenthesizedExpression(_)))
+        .is_some_and(|kind| {
+            matches!(kind, AstKind::CallExpression(call) if call.callee.without_parenthesized().span() == span)
+        })
 }
 
-fn is_somewhere_inside_component_or_hook(nodes: &AstNodes, node_id: AstNodeId) -> bool {
+fn is_somewhere_inside_component_or_hook(
+    nodes: &AstNodes,
+    node_id: AstNodeId,
+    component_wrappers: &[String],
+) -> bool {
     nodes
         .ancestors(node_id)
         .map(|id| nodes.get_node(id))
@@ -383,7 +772,7 @@ fn is_somewhere_inside_component_or_hook(nodes: &AstNodes, node_id: AstNodeId) -
         .any(|(id, ident)| {
             ident.is_some_and(|name| {
                 is_react_component_or_hook_name(name.as_str())
-                    || is_memo_or_forward_ref_callback(nodes, id)
+                    || is_component_wrapper_callback(nodes, id, component_wrappers)
             })
         })
 }
@@ -392,29 +781,38 @@ fn get_declaration_identifier<'a>(
     nodes: &'a AstNodes<'a>,
     node_id: AstNodeId,
 ) -> Option<CompactStr> {
-    nodes.ancestors(node_id).map(|id| nodes.kind(id)).find_map(|kind| {
-        match kind {
-            // const useHook = () => {};
-            AstKind::VariableDeclaration(decl) if decl.declarations.len() == 1 => {
-                decl.declarations[0].id.get_identifier().map(Atom::to_compact_str)
-            }
-            // useHook = () => {};
-            AstKind::AssignmentExpression(expr)
-                if matches!(expr.operator, AssignmentOperator::Assign) =>
-            {
-                expr.left.get_identifier().map(std::convert::Into::into)
-            }
-            // const {useHook = () => {}} = {};
-            // ({useHook = () => {}} = {});
-            AstKind::AssignmentPattern(patt) => {
-                patt.left.get_identifier().map(Atom::to_compact_str)
+    // Stop at the nearest binding, so a function bound to a pattern without a name,
+    // e.g. `const [child] = [() => {}]`, isn't named after an outer declaration.
+    nodes
+        .ancestors(node_id)
+        .map(|id| nodes.kind(id))
+        .find_map(|kind| {
+            match kind {
+                // const useHook = () => {};
+                // const a = 1, useHook = () => {};
+                AstKind::VariableDeclarator(decl) => {
+                    Some(decl.id.get_identifier().map(Atom::to_compact_str))
+                }
+                // useHook = () => {};
+                // exports.useHook = () => {};
+                // module.exports.useHook = function() {};
+                AstKind::AssignmentExpression(expr)
+                    if matches!(expr.operator, AssignmentOperator::Assign) =>
+                {
+                    Some(expr.left.get_identifier().map(std::convert::Into::into))
+                }
+                // const {useHook = () => {}} = {};
+                // ({useHook = () => {}} = {});
+                AstKind::AssignmentPattern(patt) => {
+                    Some(patt.left.get_identifier().map(Atom::to_compact_str))
+                }
+                // { useHook: () => {} }
+                // { useHook() {} }
+                AstKind::ObjectProperty(prop) => Some(prop.key.name()),
+                _ => None,
             }
-            // { useHook: () => {} }
-            // { useHook() {} }
-            AstKind::ObjectProperty(prop) => prop.key.name(),
-            _ => None,
-        }
-    })
+        })
+        .flatten()
 }
 
 fn is_export_default<'a>(nodes: &'a AstNodes<'a>, node_id: AstNodeId) -> bool {
@@ -427,10 +825,15 @@ fn is_export_default<'a>(nodes: &'a AstNodes<'a>, node_id: AstNodeId) -> bool {
 
 /// # Panics
 /// `node_id` should always point to a valid `Function`.
-fn is_memo_or_forward_ref_callback(nodes: &AstNodes, node_id: AstNodeId) -> bool {
+fn is_component_wrapper_callback(
+    nodes: &AstNodes,
+    node_id: AstNodeId,
+    component_wrappers: &[String],
+) -> bool {
     nodes.ancestors(node_id).map(|id| nodes.get_node(id)).any(|node| {
         if let AstKind::CallExpression(call) = node.kind() {
-            call.callee_name().is_some_and(|name| matches!(name, "forwardRef" | "memo"))
+            call.callee_name()
+                .is_some_and(|name| component_wrappers.iter().any(|w| w.as_str() == name))
         } else {
             false
         }
@@ -544,6 +947,37 @@ fn test() {
               useState() && a;
             }
         ",
+        // Valid because the test of a ternary is always evaluated.
+        "
+            function useHook() {
+              useState() ? a : b;
+            }
+        ",
+        // Valid because hooks before an early return are always called.
+        "
+            function useHook() {
+              if (b) {
+                console.log('true');
+              } else {
+                console.log('false');
+              }
+              useState();
+              if (a) return;
+            }
+        ",
+        // Valid because immediately invoked functions are only reported inside components and hooks.
+        "
+            (() => {
+              useState();
+            })();
+        ",
+        // Valid because the test of a ternary is always evaluated.
+        "
+            function ComponentWithHookInTernaryTest() {
+              const value = useContext(Context) ? a : b;
+              return (useState() ? c : d) ? value : null;
+            }
+        ",
         // Valid because hooks can call hooks.
         "
             function useHook() {
@@ -934,9 +1368,162 @@ fn test() {
                 });
             });
     ",
+        // Valid because calling a function can't force an early return in the caller,
+        // so the hook is still called unconditionally.
+        "
+            function useHook() {
+                a && doReturn();
+                useState();
+            }
+        ",
+        "
+            function useHook() {
+                if (a) {
+                    doReturn();
+                }
+                useState();
+            }
+        ",
+        "
+            function useHook() {
+                a ? doReturn() : doThrow();
+                useState();
+            }
+        ",
+        // Valid because the `return` only exits the callback, not the hook.
+        "
+            function useHook() {
+                items.forEach((item) => {
+                    if (item) return;
+                });
+                useState();
+            }
+        ",
+        // Valid because a `throw` aborts the render, so the hook is still called on every
+        // render which completes. This matches `eslint-plugin-react-hooks`.
+        "
+            function useHook() {
+                if (a) throw new Error();
+                useState();
+            }
+        ",
+        // Valid because `break` and `continue` only exit the loop, which is before the hook.
+        "
+            function useHook() {
+                for (const item of items) {
+                    if (item) continue;
+                    if (!item) break;
+                }
+                useState();
+            }
+        ",
+        "
+            function useHook() {
+                switch (a) {
+                    case 1:
+                        break;
+                    default:
+                        doSomething();
+                }
+                useState();
+            }
+        ",
+        // Valid because aliases are only resolved from hooks, and `use` can be conditional.
+        "
+            function Component() {
+                const f = notAHook;
+                const g = use;
+                if (a) {
+                    f();
+                    g(thing);
+                }
+            }
+        ",
+        // Valid because only `const` aliases are resolved.
+        "
+            function Component() {
+                let f = useState;
+                f = () => {};
+                if (a) {
+                    f();
+                }
+            }
+        ",
+        // Valid because a dynamic computed member can't be resolved to a hook name.
+        "
+            React[useStateName]();
+        ",
+        // Valid because components can be defined in render, and it's PascalCase.
+        "
+            function Parent() {
+                const Child = () => {
+                    useState();
+                };
+                return <Child />;
+            }
+        ",
+        // Valid because the function isn't named after the enclosing declaration.
+        "
+            const notAComponent = () => {
+                const [Child] = [() => {
+                    useState();
+                }];
+            };
+        ",
+        // Valid because the component is named by its own declarator.
+        "
+            function Parent() {
+                const a = 1, Child = () => {
+                    useState();
+                };
+                return <Child />;
+            }
+        ",
+        // Valid because functions assigned to CommonJS exports are named by the property.
+        "
+            exports.useThing = function() {
+                useState();
+            };
+        ",
+        "
+            module.exports.useThing = () => {
+                useState();
+            };
+        ",
+        "
+            module.exports = function Component() {
+                useState();
+            };
+        ",
+        // Valid because only the defaults of the assignment targets are conditional.
+        "
+            function useHook(props) {
+                let a, b;
+                [a = 1] = useState();
+                ({ [useKey()]: b = 2 } = props);
+            }
+        ",
+        // Valid because optional chaining only skips what comes after the nullish part.
+        "
+            function ComponentWithHookBeforeChain() {
+                useHook()?.value;
+                foo(useHook2())?.();
+            }
+        ",
     ];
 
     let fail = vec![
+        // Invalid because the exported function is neither a component nor a hook.
+        "
+            exports.notAHook = function() {
+                useState();
+            };
+        ",
+        "
+            module.exports = function notAComponent() {
+                useState();
+            };
+        ",
         // Invalid because it's dangerous and might not warn otherwise.
         // This *must* be invalid.
         "
@@ -1064,6 +1651,48 @@ fn test() {
                     cond ? useTernaryHook() : null;
                 }
         ",
+        // Invalid because object methods are named after their key, like variables.
+        "
+                ({ handler() { useState(); } });
+        ",
+        // Invalid because object methods are named after their key, like variables.
+        "
+                const handlers = { handler: () => { useState(); } };
+        ",
+        // Invalid because object methods are named after their key, like variables.
+        "
+                const hooks = { useHook() { useState(); }, handler() { useState(); } };
+        ",
+        // Invalid because functions called in JSX children aren't components.
+        "
+                function ComponentWithHookInJsxChild() {
+                    return <div>{(() => { useState(); })()}</div>;
+                }
+        ",
+        // Invalid because functions called in JSX children aren't components.
+        "
+                function ComponentWithHookInJsxChild() {
+                    return <div>{(function () { return useState(); })()}</div>;
+                }
+        ",
+        // Invalid because immediately invoked functions aren't components.
+        "
+                function useHookWithIife() {
+                    (() => { useState(); })();
+                }
+        ",
+        // Invalid because the alternate of a ternary is conditional, like its consequent.
+        "
+                function ComponentWithTernaryHook() {
+                    cond ? null : useTernaryHook();
+                }
+        ",
+        // Invalid because only the test of a ternary is unconditional.
+        "
+                function ComponentWithTernaryHook() {
+                    useTestHook() ? useTernaryHook() : null;
+                }
+        ",
         // Invalid because it's a common misunderstanding.
         // We *could* make it valid but the runtime error could be confusing.
         // errors: [genericError('useHookInsideCallback')],
@@ -1108,6 +1737,22 @@ fn test() {
                     return <button {...props} />
                 });
         ",
+        // Invalid because the factory of `useMemo` is a callback, not a component.
+        // errors: [genericError('useState')],
+        "
+                function ComponentWithHookInsideMemo() {
+                    const value = useMemo(() => useState(), []);
+                }
+        ",
+        // errors: [genericError('useState')],
+        "
+                function ComponentWithHookInsideMemo() {
+                    const value = React.useMemo(() => {
+                        const [state] = useState();
+                        return state;
+                    }, []);
+                }
+        ",
         // Invalid because it's a common misunderstanding.
         // We *could* make it valid but the runtime error could be confusing.
         // errors: [functionError('useState', 'handleClick')],
@@ -1215,6 +1860,8 @@ fn test() {
         // Invalid because it's dangerous and might not warn otherwise.
         // This *must* be invalid.
         // errors: [loopError('useHook1'), loopError('useHook2', true)],
+        // Upstream's `loopError` only takes the hook name and ignores the `true`,
+        // so both hooks are reported with the same loop message.
         "
             function useHookInLoops() {
                 while (a) {
@@ -1299,6 +1946,40 @@ fn test() {
                     useState();
                 }
         ",
+        // Invalid because the early return is in a branch before the hook.
+        "
+                function useHook() {
+                    if (b) {
+                        return;
+                    } else {
+                        console.log('false');
+                    }
+                    useState();
+                }
+        ",
+        // Invalid because the early return is between two branches before the hook.
+        "
+                function useHook() {
+                    if (b) {
+                        console.log('true');
+                    } else {
+                        console.log('false');
+                    }
+                    if (a) return;
+                    if (c) {
+                        console.log('c');
+                    }
+                    useState();
+                }
+        ",
+        // Invalid because only hooks after the early return are conditional.
+        "
+                function useHook() {
+                    useHookBeforeReturn();
+                    if (a) return;
+                    useHookAfterReturn();
+                }
+        ",
         // Invalid because it's dangerous and might not warn otherwise.
         // This *must* be invalid.
         // errors: [conditionalError('useHook1'), conditionalError('useHook2')],
@@ -1308,6 +1989,43 @@ fn test() {
                     b && useHook2();
                 }
         ",
+        // Invalid because optional chaining skips the call when the callee is nullish.
+        // errors: [conditionalError('useCustomHook')],
+        "
+                function useHook() {
+                    useCustomHook?.();
+                }
+        ",
+        // Invalid because optional chaining skips the call when the object is nullish.
+        // errors: [conditionalError('useState')],
+        "
+                function ComponentWithOptionalHook() {
+                    React?.useState();
+                }
+        ",
+        // Invalid because optional chaining skips the arguments along with the call.
+        // errors: [conditionalError('useHook'), conditionalError('useHook2')],
+        "
+                function ComponentWithOptionalHook() {
+                    maybe?.(useHook());
+                    a?.b.c(useHook2());
+                }
+        ",
+        // Invalid because optional chaining skips the computed property along with the access.
+        // errors: [conditionalError('useHook'), conditionalError('useHook2')],
+        "
+                function ComponentWithOptionalHook() {
+                    a?.[useHook()];
+                    a?.b[useHook2()];
+                }
+        ",
+        // Invalid because both hooks are conditional on short-circuits of the same chain.
+        // errors: [conditionalError('useHook1'), conditionalError('useHook2')],
+        "
+                function useHook() {
+                    a && useHook1() && useHook2();
+                }
+        ",
         // Invalid because it's dangerous and might not warn otherwise.
         // This *must* be invalid.
         // errors: [
@@ -1395,6 +2113,67 @@ fn test() {
             }
             useCustomHook();
         ",
+        // Invalid because the hook is only called if the condition is met.
+        // The condition is labeled.
+        "
+            function useHook() {
+                a && useState();
+            }
+        ",
+        // Invalid because hooks called through a `const` alias are still hooks.
+        "
+            function Component() {
+                const f = useState;
+                if (a) {
+                    f();
+                }
+            }
+        ",
+        "
+            const f = React.useEffect;
+            function notAComponent() {
+                f();
+            }
+        ",
+        // Invalid because event handlers are callbacks, not components.
+        // errors: [genericError('useState')],
+        "
+            function Component() {
+                return <button onClick={() => useState()} />;
+            }
+        ",
+        "
+            const Component = () => {
+                return <button onClick={function () { useState(); }} />;
+            };
+        ",
+        // Invalid because generators can't be components or hooks.
+        "
+            function* Component() {
+                useState();
+            }
+        ",
+        "
+            function* useGenerator() {
+                useState();
+            }
+        ",
+        "
+            const Component = function* () {
+                useState();
+            };
+        ",
+        "
+            function* notAComponent() {
+                useState();
+            }
+        ",
+        // Invalid because static computed member names are resolved.
+        // errors: [topLevelError('useState'), topLevelError('useEffect')],
+        "
+            React[\"useState\"]();
+            React[`useEffect`](() => {});
+        ",
         // Technically this is a false positive.
         // We *could* make it valid (and it used to be).
         //
@@ -1488,6 +2267,14 @@ fn test() {
                 }
             }
         ",
+        // errors: [classError('useState')],
+        "
+            class C {
+                static {
+                    useState();
+                }
+            }
+        ",
         // errors: [asyncComponentHookError('use')],
         "
             async function AsyncComponent() {
@@ -1524,6 +2311,95 @@ fn test() {
                 }
             }
         ",
+        // errors: [loopError('useHookInsideLoop')],
+        "
+            function ComponentWithHookInsideDoWhile() {
+                do {
+                    useHookInsideLoop();
+                } while (cond);
+            }
+        ",
+        // errors: [loopError('useHookInsideLoop')],
+        "
+            function ComponentWithHookInsideInfiniteLoop() {
+                while (true) {
+                    useHookInsideLoop();
+                }
+            }
+        ",
+        // errors: [loopError('useHookInsideLoop')],
+        "
+            function ComponentWithHookInsideInfiniteLoop() {
+                while (true) {
+                    if (cond) break;
+                    useHookInsideLoop();
+                }
+            }
+        ",
+        // errors: [loopError('useHookInsideLoop')],
+        "
+            function ComponentWithHookInsideInfiniteLoop() {
+                for (;;) {
+                    useHookInsideLoop();
+                }
+            }
+        ",
+        // Invalid because default values are only evaluated if the argument is `undefined`.
+        // errors: [conditionalError('useState')],
+        "
+            function Component(x = useState()) {
+                return x;
+            }
+        ",
+        // errors: [conditionalError('useContext')],
+        "
+            const Component = ({ value = useContext(Context) }) => {
+                return value;
+            };
+        ",
+        // errors: [conditionalError('useState')],
+        "
+            function useHook(props) {
+                const { value = useState() } = props;
+                return value;
+            }
+        ",
+        // errors: [conditionalError('useState')],
+        "
+            function useHook(props) {
+                let value;
+                ({ value = useState() } = props);
+                return value;
+            }
+        ",
+        // errors: [conditionalError('useState')],
+        "
+            function useHook(props) {
+                let value;
+                ({ a: value = useState() } = props);
+                return value;
+            }
+        ",
+        // errors: [conditionalError('useState')],
+        "
+            function useHook(arr) {
+                let value;
+                [value = useState()] = arr;
+                return value;
+            }
+        ",
+        // Invalid because a `return` on a branch makes the hook conditional.
+        // errors: [conditionalError('useState', true)],
+        "
+            function useHook() {
+                if (a) {
+                    return;
+                } else {
+                    doSomething();
+                }
+                useState();
+            }
+        ",
         // TODO: This should error but doesn't.
         // Original rule also fails to raise this error.
         // errors: [genericError('useState')],
@@ -1534,7 +2410,202 @@ fn test() {
         //         });
         //     }
         // " ,
+        // Invalid because a function defined in render with a camelCase name isn't a component.
+        // errors: [functionError('useState', 'child')],
+        "
+            function Parent() {
+                const child = () => {
+                    useState();
+                };
+                return child();
+            }
+        ",
+        // errors: [functionError('useState', 'child')],
+        "
+            function Parent() {
+                const a = 1, child = () => {
+                    useState();
+                };
+            }
+        ",
     ];
 
+    let observer = || Some(serde_json::json!([{ "componentWrappers": ["observer"] }]));
+    let ignore_fake_timers = || Some(serde_json::json!([{ "ignoreHooks": ["useFakeTimers"] }]));
+    let no_cfg = || Some(serde_json::json!([{ "maxCfgBlocks": 0 }]));
+
+    let pass = pass
+        .into_iter()
+        .map(|code| (code, None))
+        .chain([
+            // Valid because callbacks of configured component wrappers are components.
+            (
+                "
+                const Foo = observer(() => {
+                    useState();
+                });
+                ",
+                observer(),
+            ),
+            (
+                "
+                const Foo = observer(function () {
+                    useState();
+                });
+                ",
+                observer(),
+            ),
+            // Valid because `useFakeTimers` is configured as not being a hook.
+            (
+                "
+                function ComponentWithFakeTimers() {
+                    if (a) {
+                        useFakeTimers();
+                    }
+                }
+                ",
+                ignore_fake_timers(),
+            ),
+            // Valid because above `maxCfgBlocks` only branches around the hook are checked.
+            (
+                "
+                function ComponentWithCheapAnalysis() {
+                    if (a) {
+                        foo();
+                    }
+                    useState();
+                }
+                ",
+                no_cfg(),
+            ),
+            // Valid because above `maxCfgBlocks` the test of a ternary is unconditional too.
+            (
+                "
+                function ComponentWithCheapAnalysis() {
+                    useState() ? a : b;
+                }
+                ",
+                no_cfg(),
+            ),
+            // Valid because above `maxCfgBlocks` breaking out of an earlier loop doesn't skip the hook.
+            (
+                "
+                function ComponentWithCheapAnalysis() {
+                    for (const x of xs) {
+                        if (x) break;
+                    }
+                    useState();
+                }
+                ",
+                no_cfg(),
+            ),
+        ])
+        .collect::<Vec<_>>();
+
+    let fail = fail
+        .into_iter()
+        .map(|code| (code, None))
+        .chain([
+            // Invalid because `observer` is not a component wrapper by default.
+            (
+                "
+                const Foo = observer(() => {
+                    useState();
+                });
+                ",
+                None,
+            ),
+            // Invalid because configuring `componentWrappers` replaces the defaults.
+            (
+                "
+                const Foo = memo(() => {
+                    useState();
+                });
+                ",
+                observer(),
+            ),
+            // Invalid because `ignoreHooks` only ignores the configured names.
+            (
+                "
+                function ComponentWithFakeTimers() {
+                    if (a) {
+                        useFakeTimers();
+                        useState();
+                    }
+                }
+                ",
+                ignore_fake_timers(),
+            ),
+            // Invalid because hooks in branches are still reported above `maxCfgBlocks`.
+            (
+                "
+                function ComponentWithCheapAnalysis() {
+                    if (a) {
+                        useState();
+                    }
+                }
+                ",
+                no_cfg(),
+            ),
+            // Invalid because hooks after early exits are still reported above `maxCfgBlocks`.
+            (
+                "
+                function ComponentWithCheapAnalysis() {
+                    if (a) {
+                        return;
+                    }
+                    useState();
+                }
+                ",
+                no_cfg(),
+            ),
+            // Invalid because `maxCfgBlocks` is compared with the size of the hook's function,
+            // so other functions in the file don't stop it from being fully analysed.
+            (
+                "
+                function ComponentWithFullAnalysis() {
+                    switch (a) { case 1: return; }
+                    useState();
+                }
+                function manyBranches() {
+                    if (a) b(); if (c) d(); if (e) f(); if (g) h();
+                    if (i) j(); if (k) l(); if (m) n(); if (o) p();
+                    if (q) r(); if (s) t(); if (u) v(); if (w) x();
+                }
+                ",
+                Some(serde_json::json!([{ "maxCfgBlocks": 20 }])),
+            ),
+        ])
+        .collect::<Vec<_>>();
+
     Tester::new(RulesOfHooks::NAME, pass, fail).test_and_snapshot();
 }
+
+#[test]
+fn test_large_component() {
+    use crate::tester::Tester;
+
+    // A component with `branches` ifs, whose hook is only reported by the full analysis,
+    // as the early exit before it is in a `switch` rather than an `if`.
+    let component = |branches: usize| {
+        let branches = "if (x) { foo(); }\n".repeat(branches);
+        format!(
+            "function Component() {{\nswitch (a) {{ case 1: return; }}\n{branches}useState();\n}}"
+        )
+    };
+    let max_cfg_blocks = |n: usize| Some(serde_json::json!([{ "maxCfgBlocks": n }]));
+
+    // Thousands of branches are above the default `maxCfgBlocks`, and 10 are below it.
+    let huge = component(5000);
+    let small = component(10);
+    Tester::new(RulesOfHooks::NAME, vec![huge.as_str()], vec![small.as_str()]).test();
+
+    // 100 branches are above a `maxCfgBlocks` of 50, and below one of 1000.
+    let medium = component(100);
+    Tester::new(
+        RulesOfHooks::NAME,
+        vec![(medium.as_str(), max_cfg_blocks(50))],
+        vec![(medium.as_str(), max_cfg_blocks(1000)), (medium.as_str(), None)],
+    )
+    .test();
+}
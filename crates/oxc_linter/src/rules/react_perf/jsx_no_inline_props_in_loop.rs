@@ -0,0 +1,169 @@
+use oxc_ast::{
+    ast::{Expression, JSXAttributeValue, JSXElement},
+    AstKind,
+};
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::get_prop_value, AstNode};
+
+fn jsx_no_inline_props_in_loop_diagnostic(span0: Span, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint-plugin-react-perf(jsx-no-inline-props-in-loop): JSX attribute values should not contain objects or arrays created inside a loop.")
+        .with_help(r"move the value out of the loop, or memoize it (https://react.dev/reference/react/memo#my-component-rerenders-when-a-prop-is-an-object-or-array).")
+        .with_labels([
+            span0.into(),
+            LabeledSpan::new_with_span(Some("Loop starts here".into()), span1),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct JsxNoInlinePropsInLoop;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prevent object and array literals from being used as values of JSX props
+    /// for elements created in a loop or in an array iteration callback.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Every iteration creates a new object or array, so every element in the list
+    /// receives props which are not equal to those of the previous render, and re-renders.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// items.map(item => <Item key={item.id} style={{ color: 'red' }} />)
+    /// for (const item of items) { list.push(<Item tags={[item.tag]} />) }
+    ///
+    /// // Good
+    /// const style = { color: 'red' };
+    /// items.map(item => <Item key={item.id} style={style} />)
+    /// ```
+    JsxNoInlinePropsInLoop,
+    perf
+);
+
+impl Rule for JsxNoInlinePropsInLoop {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXElement(jsx_elem) = node.kind() else { return };
+        let Some(inline_props) = find_inline_props(jsx_elem) else { return };
+        let Some(loop_span) = find_enclosing_loop(node, ctx) else { return };
+        for span in inline_props {
+            ctx.diagnostic(jsx_no_inline_props_in_loop_diagnostic(span, loop_span));
+        }
+    }
+}
+
+/// Spans of the attribute values of `jsx_elem` which are object or array literals.
+fn find_inline_props(jsx_elem: &JSXElement) -> Option<Vec<Span>> {
+    let spans = jsx_elem
+        .opening_element
+        .attributes
+        .iter()
+        .filter_map(|item| match get_prop_value(item) {
+            Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                match container.expression.as_expression()?.without_parenthesized() {
+                    Expression::ObjectExpression(expr) => Some(expr.span),
+                    Expression::ArrayExpression(expr) => Some(expr.span),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    (!spans.is_empty()).then_some(spans)
+}
+
+/// Span of the keyword of the loop statement, or of the method name of the array iteration call,
+/// which `node` is created in,
+/// without crossing into an enclosing function which isn't an iteration callback.
+fn find_enclosing_loop(node: &AstNode, ctx: &LintContext) -> Option<Span> {
+    let span = node.kind().span();
+    for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+        match parent.kind() {
+            AstKind::ForStatement(stmt) if contains(stmt.body.span(), span) => {
+                return Some(keyword_span(stmt.span, "for"));
+            }
+            AstKind::ForInStatement(stmt) if contains(stmt.body.span(), span) => {
+                return Some(keyword_span(stmt.span, "for"));
+            }
+            AstKind::ForOfStatement(stmt) if contains(stmt.body.span(), span) => {
+                return Some(keyword_span(stmt.span, "for"));
+            }
+            AstKind::WhileStatement(stmt) if contains(stmt.body.span(), span) => {
+                return Some(keyword_span(stmt.span, "while"));
+            }
+            AstKind::DoWhileStatement(stmt) if contains(stmt.body.span(), span) => {
+                return Some(keyword_span(stmt.span, "do"));
+            }
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                return get_iteration_call_span(parent, ctx);
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Span of the method name if `func` is the callback of an array iteration method,
+/// e.g. `items.map(func)` or `Array.from(items, func)`.
+fn get_iteration_call_span(func: &AstNode, ctx: &LintContext) -> Option<Span> {
+    let argument = ctx.nodes().parent_node(func.id())?;
+    if !matches!(argument.kind(), AstKind::Argument(_)) {
+        return None;
+    }
+    let AstKind::CallExpression(call) = ctx.nodes().parent_kind(argument.id())? else {
+        return None;
+    };
+    let member = call.callee.as_member_expression()?;
+    let (span, name) = member.static_property_info()?;
+    let is_iteration = match name {
+        "map" | "flatMap" | "forEach" => true,
+        "from" => member.object().is_specific_id("Array"),
+        _ => false,
+    };
+    is_iteration.then_some(span)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn keyword_span(stmt_span: Span, keyword: &str) -> Span {
+    Span::new(stmt_span.start, stmt_span.start + keyword.len() as u32)
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r"<Item style={{ color: 'red' }} />",
+        r"items.map(item => <Item key={item.id} style={style} />)",
+        r"items.map(item => <Item key={item.id} onClick={() => select(item)} />)",
+        r"for (const item of items) { list.push(<Item key={item.id} value={item} />) }",
+        r"for (const item of [<Item style={{}} />]) {}",
+        r"items.map(item => { const render = () => <Item style={{}} />; return render; })",
+        r"items.filter(item => <Item style={{}} />)",
+        r"Observable.from(items, item => <Item style={{}} />)",
+        r"items.from(item => <Item style={{}} />)",
+    ];
+
+    let fail = vec![
+        r"items.map(item => <Item key={item.id} style={{ color: 'red' }} />)",
+        r"items.map(function (item) { return <Item key={item.id} tags={[item.tag]} />; })",
+        r"Array.from(items, item => <Item options={{ item }} />)",
+        r"items.forEach(item => { list.push(<Item style={({})} tags={[]} />); })",
+        r"for (const item of items) { list.push(<Item style={{}} />) }",
+        r"for (let i = 0; i < n; i++) { list.push(<Item tags={[i]} />) }",
+        r"while (next()) { list.push(<Item style={{}} />) }",
+    ];
+
+    Tester::new(JsxNoInlinePropsInLoop::NAME, pass, fail)
+        .with_react_perf_plugin(true)
+        .test_and_snapshot();
+}
@@ -1,7 +1,7 @@
 use oxc_ast::{
     ast::{
         CallExpression, Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
-        JSXChild, JSXElement, JSXElementName, JSXExpression, JSXOpeningElement, MemberExpression,
+        JSXChild, JSXElement, JSXElementName, JSXExpression, JSXOpeningElement,
     },
     match_member_expression, AstKind,
 };
@@ -300,10 +300,11 @@ pub fn is_react_hook(expr: &Expression) -> bool {
             // `match_member_expression` macro.
             #[allow(unsafe_code)]
             let expr = unsafe { expr.as_member_expression().unwrap_unchecked() };
-            let MemberExpression::StaticMemberExpression(static_expr) = expr else { return false };
+            // `React.useState` or `React["useState"]`
+            let Some(property_name) = expr.static_property_name() else { return false };
 
-            let is_valid_property = is_react_hook_name(&static_expr.property.name);
-            let is_valid_namespace = match &static_expr.object {
+            let is_valid_property = is_react_hook_name(property_name);
+            let is_valid_namespace = match expr.object() {
                 Expression::Identifier(ident) => {
                     // TODO: test PascalCase
                     ident.name.chars().next().is_some_and(char::is_uppercase)
@@ -0,0 +1,63 @@
+use oxc_ast::ast::IdentifierReference;
+use oxc_span::{Atom, Span};
+use oxc_syntax::scope::ScopeId;
+use oxc_traverse::TraverseCtx;
+
+/// An identifier reference created by a transform, which is not bound to any symbol yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedReference<'a> {
+    pub name: Atom<'a>,
+    /// Span of the reference, which is empty if it doesn't come from the source text,
+    /// e.g. for `_jsx`.
+    pub span: Span,
+    /// Scope the reference is in, to resolve it from.
+    pub scope_id: ScopeId,
+}
+
+/// Collects the identifier references created by transforms, e.g. `_jsx` and `React`,
+/// so that a later pass can bind them to their symbols.
+///
+/// This includes references to components, e.g. `Foo` for `<Foo />`: the JSX transform creates
+/// them from the element names, so they need binding too.
+///
+/// Semantic analysis runs before the transforms, so every reference in the original program
+/// has a `ReferenceId`. A reference without one must have been created since.
+///
+/// Call [`GeneratedReferences::record`] from `Traverse::enter_identifier_reference`.
+/// Replacements made in `enter_*` are walked, so references in them are seen.
+/// Replacements made in `exit_*` are not, so record the references in them as they are made.
+#[derive(Debug, Default)]
+pub struct GeneratedReferences<'a> {
+    references: Vec<GeneratedReference<'a>>,
+}
+
+impl<'a> GeneratedReferences<'a> {
+    /// Record `ident` if it was created by a transform, in the current scope of `ctx`.
+    pub fn record(&mut self, ident: &IdentifierReference<'a>, ctx: &TraverseCtx<'a>) {
+        if ident.reference_id.get().is_none() {
+            self.references.push(GeneratedReference {
+                name: ident.name.clone(),
+                span: ident.span,
+                scope_id: ctx.current_scope_id(),
+            });
+        }
+    }
+
+    /// Number of references recorded.
+    pub fn len(&self) -> usize {
+        self.references.len()
+    }
+
+    /// Move the references recorded from `index` on to `scope_id`, e.g. when the expression
+    /// they are in is hoisted to the top level.
+    pub fn move_to_scope(&mut self, index: usize, scope_id: ScopeId) {
+        for reference in &mut self.references[index..] {
+            reference.scope_id = scope_id;
+        }
+    }
+
+    /// The recorded references, in the order they were visited.
+    pub fn into_references(self) -> Vec<GeneratedReference<'a>> {
+        self.references
+    }
+}
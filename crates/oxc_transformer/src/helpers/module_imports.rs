@@ -5,6 +5,7 @@ use oxc_allocator::{Allocator, Vec};
 use oxc_ast::{ast::*, AstBuilder};
 use oxc_span::{CompactStr, SPAN};
 
+#[derive(Clone)]
 pub struct NamedImport {
     imported: CompactStr,
     local: Option<CompactStr>, // Not used in `require`
@@ -79,6 +80,34 @@ impl<'a> ModuleImports<'a> {
         ))
     }
 
+    /// Build and remove the import statements added so far from `sources`,
+    /// leaving the imports from other sources to [`ModuleImports::get_import_statements`].
+    pub fn take_import_statements_from(&self, sources: &[CompactStr]) -> Vec<'a, Statement<'a>> {
+        let mut imports = self.imports.borrow_mut();
+        let (taken, kept): (IndexMap<_, _>, IndexMap<_, _>) = std::mem::take(&mut *imports)
+            .into_iter()
+            .partition(|(import_type, _)| sources.contains(&import_type.source));
+        *imports = kept;
+        self.ast.new_vec_from_iter(taken.into_iter().map(|(import_type, names)| {
+            match import_type.kind {
+                ImportKind::Import => self.get_named_import(&import_type.source, names),
+                ImportKind::Require => self.get_require(&import_type.source, names),
+            }
+        }))
+    }
+
+    /// Build the import statements added so far from `sources`, without removing them,
+    /// so they are still inserted by [`ModuleImports::get_import_statements`].
+    pub fn pending_import_statements_from(&self, sources: &[CompactStr]) -> Vec<'a, Statement<'a>> {
+        let imports = self.imports.borrow();
+        let pending =
+            imports.iter().filter(|(import_type, _)| sources.contains(&import_type.source));
+        self.ast.new_vec_from_iter(pending.map(|(import_type, names)| match import_type.kind {
+            ImportKind::Import => self.get_named_import(&import_type.source, names.clone()),
+            ImportKind::Require => self.get_require(&import_type.source, names.clone()),
+        }))
+    }
+
     fn get_named_import(
         &self,
         source: &CompactStr,
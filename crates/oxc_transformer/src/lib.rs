@@ -19,6 +19,7 @@ mod react;
 mod typescript;
 
 mod helpers {
+    pub mod generated_references;
     pub mod module_imports;
 }
 
@@ -33,8 +34,9 @@ use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
 
 pub use crate::{
     compiler_assumptions::CompilerAssumptions, env::EnvOptions, es2015::ES2015Options,
-    options::BabelOptions, options::TransformOptions, react::ReactOptions,
-    typescript::TypeScriptOptions,
+    helpers::generated_references::GeneratedReference, options::BabelOptions,
+    options::TransformOptions, react::transform_react_jsx, react::ReactJsx, react::ReactJsxReturn,
+    react::ReactOptions, react::RuntimeFeatures, typescript::TypeScriptOptions,
 };
 
 use crate::{
@@ -149,7 +151,8 @@ impl<'a> Traverse<'a> for Transformer<'a> {
         self.x3_es2015.transform_expression(expr);
     }
 
-    fn exit_expression(&mut self, expr: &mut Expression<'a>, _ctx: &mut TraverseCtx<'a>) {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x1_react.transform_expression_on_exit(expr, ctx);
         self.x3_es2015.transform_expression_on_exit(expr);
     }
 
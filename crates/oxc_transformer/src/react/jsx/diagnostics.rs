@@ -24,3 +24,15 @@ pub fn valueless_key(span0: Span) -> OxcDiagnostic {
 pub fn spread_children_are_not_supported(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Spread children are not supported in React.").with_labels([span0.into()])
 }
+
+pub fn empty_expression_child_dropped(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Empty expression children are dropped, including any comments in them.")
+        .with_help("Remove the empty expression, or disable `strictChildren`.")
+        .with_labels([span0.into()])
+}
+
+pub fn classic_runtime_with_runtime_import(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("The classic runtime is used, but the automatic runtime is imported.")
+        .with_help("Use the automatic runtime with `@jsxRuntime automatic` or the `runtime` option, or remove the import.")
+        .with_labels([span0.into()])
+}
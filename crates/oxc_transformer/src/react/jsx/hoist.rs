@@ -0,0 +1,144 @@
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+use oxc_syntax::{scope::ScopeFlags, symbol::SymbolFlags};
+use oxc_traverse::{FinderRet, TraverseCtx};
+
+use super::ReactJsx;
+
+/// Hoist constant elements and props to the top level, see
+/// [`super::ReactOptions::hoist_constant_elements`] and [`super::ReactOptions::hoist_constant_props`].
+impl<'a> ReactJsx<'a> {
+    /// Whether `e` should be hoisted, i.e. it is constant and inside a function.
+    pub(super) fn should_hoist_element(&self, e: &JSXElement<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        self.options.hoist_constant_elements
+            && !self.options.development
+            && Self::is_constant_element(e)
+            && Self::is_inside_function(ctx)
+    }
+
+    /// Whether the props object `props` should be hoisted, i.e. it is not empty,
+    /// its values are literals and it is inside a function.
+    pub(super) fn should_hoist_props(
+        &self,
+        props: &ObjectExpression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let is_constant_property = |property: &ObjectPropertyKind<'a>| {
+            let ObjectPropertyKind::ObjectProperty(property) = property else { return false };
+            !property.computed
+                && matches!(
+                    property.value,
+                    Expression::StringLiteral(_)
+                        | Expression::NumericLiteral(_)
+                        | Expression::BooleanLiteral(_)
+                        | Expression::NullLiteral(_)
+                )
+        };
+
+        self.options.hoist_constant_props
+            && !props.properties.is_empty()
+            && props.properties.iter().all(is_constant_property)
+            && Self::is_inside_function(ctx)
+    }
+
+    /// Declare `expr` as a `const` named after `name` at the top level, and return a reference to it.
+    pub(super) fn hoist_to_top_level(
+        &mut self,
+        name: &str,
+        expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let root_scope_id = ctx.scopes().root_scope_id();
+        let flags = SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable;
+        let name = self.ast().new_atom(&ctx.generate_uid(name, root_scope_id, flags));
+
+        let var_kind = VariableDeclarationKind::Const;
+        let id = {
+            let ident = BindingIdentifier::new(SPAN, name.clone());
+            let ident = self.ast().binding_pattern_identifier(ident);
+            self.ast().binding_pattern(ident, None, false)
+        };
+        let decl = self.ast().variable_declarator(SPAN, var_kind, id, Some(expr), false);
+        let decl = self.ast().new_vec_single(decl);
+        let var_decl = self.ast().variable_declaration(SPAN, var_kind, decl, Modifiers::empty());
+        self.hoisted.push(Statement::VariableDeclaration(var_decl));
+
+        self.ast().identifier_reference_expression(IdentifierReference::new(SPAN, name))
+    }
+
+    /// Insert the hoisted declarations after the imports in `program`, in the order they were found.
+    pub(super) fn insert_hoisted(&mut self, program: &mut Program<'a>) {
+        if self.hoisted.is_empty() {
+            return;
+        }
+        let index = program
+            .body
+            .iter()
+            .rposition(|stmt| matches!(stmt, Statement::ImportDeclaration(_)))
+            .map_or(0, |i| i + 1);
+        program.body.splice(index..index, self.hoisted.drain(..));
+    }
+
+    fn is_inside_function(ctx: &TraverseCtx<'a>) -> bool {
+        ctx.find_scope_by_flags(|flags| {
+            if flags.contains(ScopeFlags::Function) {
+                FinderRet::Found(())
+            } else {
+                FinderRet::Continue
+            }
+        })
+        .is_some()
+    }
+
+    /// Constant means:
+    /// - Elements are host elements (e.g. `div`, not `Foo` or `foo.bar`).
+    /// - Attributes are literals or valueless, and are not `ref`.
+    /// - Children are text, empty expressions or literals, or constant elements and fragments.
+    fn is_constant_element(e: &JSXElement<'a>) -> bool {
+        let JSXElementName::Identifier(ident) = &e.opening_element.name else { return false };
+        if !ident.name.starts_with(|c: char| c.is_ascii_lowercase()) {
+            return false;
+        }
+
+        let is_constant_attribute = |attribute: &JSXAttributeItem<'a>| {
+            let JSXAttributeItem::Attribute(attr) = attribute else { return false };
+            if attr.is_identifier("ref") {
+                return false;
+            }
+            match &attr.value {
+                None | Some(JSXAttributeValue::StringLiteral(_)) => true,
+                Some(JSXAttributeValue::ExpressionContainer(c)) => {
+                    Self::is_constant_expression(&c.expression)
+                }
+                Some(_) => false,
+            }
+        };
+
+        e.opening_element.attributes.iter().all(is_constant_attribute)
+            && Self::is_constant_children(&e.children)
+    }
+
+    fn is_constant_children(children: &Vec<'a, JSXChild<'a>>) -> bool {
+        children.iter().all(|child| match child {
+            JSXChild::Text(_) => true,
+            JSXChild::Element(e) => Self::is_constant_element(e),
+            JSXChild::Fragment(e) => Self::is_constant_children(&e.children),
+            JSXChild::ExpressionContainer(c) => {
+                matches!(c.expression, JSXExpression::EmptyExpression(_))
+                    || Self::is_constant_expression(&c.expression)
+            }
+            JSXChild::Spread(_) => false,
+        })
+    }
+
+    fn is_constant_expression(expr: &JSXExpression<'a>) -> bool {
+        matches!(
+            expr,
+            JSXExpression::StringLiteral(_)
+                | JSXExpression::NumericLiteral(_)
+                | JSXExpression::BooleanLiteral(_)
+                | JSXExpression::NullLiteral(_)
+        )
+    }
+}
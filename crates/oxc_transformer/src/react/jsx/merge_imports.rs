@@ -0,0 +1,84 @@
+use oxc_ast::ast::*;
+
+use super::ReactJsx;
+
+/// Merge runtime imports, see [`super::ReactOptions::merge_runtime_imports`].
+impl<'a> ReactJsx<'a> {
+    /// Collapse the named imports from the JSX runtime and from `importSource` into one
+    /// statement per source, at the position of the first one, dropping duplicate specifiers.
+    ///
+    /// This covers imports which were already in the program, e.g. from modules concatenated
+    /// before being transformed, as well as the ones added by this plugin.
+    ///
+    /// Imports with default or namespace specifiers, type imports and imports with attributes
+    /// are left as they are.
+    pub(super) fn merge_runtime_imports(&self, program: &mut Program<'a>) {
+        let sources =
+            [self.jsx_runtime_importer.to_string(), self.options.import_source.to_string()];
+        for source in &sources {
+            Self::merge_imports_from(program, source);
+        }
+    }
+
+    fn merge_imports_from(program: &mut Program<'a>, source: &str) {
+        let indexes = program
+            .body
+            .iter()
+            .enumerate()
+            .filter_map(|(index, stmt)| match stmt {
+                Statement::ImportDeclaration(decl) if is_mergeable(decl, source) => Some(index),
+                _ => None,
+            })
+            .collect::<std::vec::Vec<_>>();
+        let Some((&first, rest)) = indexes.split_first() else { return };
+        if rest.is_empty() {
+            return;
+        }
+
+        let mut moved = vec![];
+        for &index in rest {
+            if let Some(Statement::ImportDeclaration(decl)) = program.body.get_mut(index) {
+                moved.extend(decl.specifiers.take().into_iter().flatten());
+            }
+        }
+
+        if let Some(Statement::ImportDeclaration(decl)) = program.body.get_mut(first) {
+            if let Some(specifiers) = &mut decl.specifiers {
+                for specifier in moved {
+                    if !specifiers.iter().any(|existing| is_same_specifier(existing, &specifier)) {
+                        specifiers.push(specifier);
+                    }
+                }
+            }
+        }
+
+        let mut index = 0;
+        program.body.retain(|_| {
+            let keep = !rest.contains(&index);
+            index += 1;
+            keep
+        });
+    }
+}
+
+/// `import { a, b as c } from "source"`
+fn is_mergeable(decl: &ImportDeclaration, source: &str) -> bool {
+    decl.source.value == source
+        && decl.import_kind.is_value()
+        && decl.with_clause.is_none()
+        && decl.specifiers.as_ref().is_some_and(|specifiers| {
+            specifiers.iter().all(|specifier| {
+                matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(s) if s.import_kind.is_value())
+            })
+        })
+}
+
+fn is_same_specifier(a: &ImportDeclarationSpecifier, b: &ImportDeclarationSpecifier) -> bool {
+    match (a, b) {
+        (
+            ImportDeclarationSpecifier::ImportSpecifier(a),
+            ImportDeclarationSpecifier::ImportSpecifier(b),
+        ) => a.imported.name() == b.imported.name() && a.local.name == b.local.name,
+        _ => false,
+    }
+}
@@ -1,24 +1,32 @@
 mod diagnostics;
+mod hoist;
+mod merge_imports;
+mod runtime_features;
+mod standalone;
+mod to_string;
 
 use std::rc::Rc;
 
 use oxc_allocator::Vec;
 use oxc_ast::{ast::*, AstBuilder};
-use oxc_span::{CompactStr, GetSpan, Span, SPAN};
+use oxc_span::{Atom, CompactStr, GetSpan, Span, SPAN};
 use oxc_syntax::{
     identifier::{is_irregular_whitespace, is_line_terminator},
     xml_entities::XML_ENTITIES,
 };
 use oxc_traverse::TraverseCtx;
+use rustc_hash::FxHashSet;
 
 use crate::{context::Ctx, helpers::module_imports::NamedImport};
 
-use super::utils::get_line_column;
 pub use super::{
     jsx_self::ReactJsxSelf,
     jsx_source::ReactJsxSource,
-    options::{ReactJsxRuntime, ReactOptions},
+    options::{BooleanShorthandValue, EmptyPropsValue, ReactJsxRuntime, ReactOptions},
 };
+pub use runtime_features::RuntimeFeatures;
+pub use standalone::{transform_react_jsx, ReactJsxReturn};
+use to_string::escape_html;
 
 /// [plugin-transform-react-jsx](https://babeljs.io/docs/babel-plugin-transform-react-jsx)
 ///
@@ -26,12 +34,18 @@ pub use super::{
 ///
 /// This plugin is included in `preset-react`.
 ///
+/// It holds the file's context and which runtime imports were added, so it is created for each
+/// file, or moved on to the next one with [`ReactJsx::begin_file`], and imports never carry over
+/// from one file to the next.
+///
 /// References:
 ///
 /// * <https://babeljs.io/docs/babel-plugin-transform-react-jsx>
 /// * <https://github.com/babel/babel/tree/main/packages/babel-helper-builder-react-jsx>
 pub struct ReactJsx<'a> {
     options: Rc<ReactOptions>,
+    /// `options` before the file's pragmas were applied, for the next file
+    base_options: Rc<ReactOptions>,
 
     ctx: Ctx<'a>,
 
@@ -46,11 +60,25 @@ pub struct ReactJsx<'a> {
     import_jsxs: bool,
     import_fragment: bool,
     import_create_element: bool,
+    /// Sources of the imports and requires added to `ctx.module_imports`
+    import_sources: std::vec::Vec<CompactStr>,
+
+    // Stats
+    elements_count: usize,
+    fragments_count: usize,
+    used_components: FxHashSet<Atom<'a>>,
+    runtime_features: RuntimeFeatures,
+
+    /// Number of expressions entered and not yet exited since the transformed element to hoist,
+    /// which is hoisted when this is back to 0 and it is exited
+    pending_hoist: Option<usize>,
+    /// `const` declarations of elements and props hoisted to the top level
+    hoisted: std::vec::Vec<Statement<'a>>,
 }
 
 // Transforms
 impl<'a> ReactJsx<'a> {
-    pub fn new(options: &Rc<ReactOptions>, ctx: &Ctx<'a>) -> Self {
+    pub(crate) fn new(options: &Rc<ReactOptions>, ctx: &Ctx<'a>) -> Self {
         let default_runtime = options.runtime;
         let jsx_runtime_importer =
             if options.import_source == "react" || default_runtime.is_classic() {
@@ -67,38 +95,129 @@ impl<'a> ReactJsx<'a> {
 
         Self {
             options: Rc::clone(options),
+            base_options: Rc::clone(options),
             ctx: Rc::clone(ctx),
             jsx_self: ReactJsxSelf::new(ctx),
-            jsx_source: ReactJsxSource::new(ctx),
+            jsx_source: ReactJsxSource::new(options, ctx),
             require_jsx_runtime: false,
             jsx_runtime_importer,
             import_jsx: false,
             import_jsxs: false,
             import_fragment: false,
             import_create_element: false,
+            import_sources: vec![],
+            elements_count: 0,
+            fragments_count: 0,
+            used_components: FxHashSet::default(),
+            runtime_features: RuntimeFeatures::empty(),
+            pending_hoist: None,
+            hoisted: vec![],
         }
     }
 
     pub fn transform_program_on_exit(&mut self, program: &mut Program<'a>) {
+        // Inserted first, so the runtime imports go before them
+        self.insert_hoisted(program);
         self.add_runtime_imports(program);
+        if self.options.merge_runtime_imports {
+            self.merge_runtime_imports(program);
+        }
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(depth) = &mut self.pending_hoist {
+            *depth += 1;
+        }
+        match expr {
+            Expression::JSXElement(e) => {
+                let should_hoist =
+                    self.pending_hoist.is_none() && self.should_hoist_element(e, ctx);
+                *expr = self.transform_jsx_element(e, ctx);
+                // Elements left as JSX by `ReactOptions::should_transform` are not hoisted
+                if should_hoist && !matches!(expr, Expression::JSXElement(_)) {
+                    self.pending_hoist = Some(0);
+                }
+            }
+            Expression::JSXFragment(e) => *expr = self.transform_jsx_fragment(e, ctx),
+            _ => {}
+        }
+    }
+
+    pub fn transform_expression_on_exit(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Hoisted after its children are traversed, so other plugins see them as usual,
+        // e.g. the references to the runtime imports which keep them from being removed.
+        // Expressions are entered and exited in nested order, so this is the element's
+        // expression, even if it was wrapped by `ReactOptions::wrap_calls` or another plugin.
+        match &mut self.pending_hoist {
+            Some(0) => {
+                self.pending_hoist = None;
+                let element = self.ast().move_expression(expr);
+                *expr = self.hoist_to_top_level("ref", element, ctx);
+            }
+            Some(depth) => *depth -= 1,
+            None => {}
+        }
     }
 
+    /// Return the runtime call for `e`, e.g. `_jsx("div", {})` for `<div />`, without replacing it
+    /// like [`Self::transform_expression`] does.
     pub fn transform_jsx_element(
         &mut self,
         e: &JSXElement<'a>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
         self.transform_jsx(&JSXElementOrFragment::Element(e), ctx)
     }
 
+    /// Return the runtime call for `e`, e.g. `_jsx(_Fragment, {})` for `<></>`, without replacing
+    /// it like [`Self::transform_expression`] does.
     pub fn transform_jsx_fragment(
         &mut self,
         e: &JSXFragment<'a>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
         self.transform_jsx(&JSXElementOrFragment::Fragment(e), ctx)
     }
 
+    /// Names of the components instantiated by the transformed JSX, e.g. `Foo` and `Bar.Baz`
+    /// for `<Foo />` and `<Bar.Baz />`.
+    ///
+    /// Host elements such as `<div />` are not included.
+    pub fn used_components(&self) -> &FxHashSet<Atom<'a>> {
+        &self.used_components
+    }
+
+    /// The functions of the automatic runtime called by the transformed JSX so far,
+    /// e.g. `JSX | FRAGMENT` for `<><div /></>`.
+    ///
+    /// These are recorded even if [`ReactOptions::inject_imports`] is disabled, and are empty
+    /// in the classic runtime.
+    pub fn emitted_runtime_functions(&self) -> RuntimeFeatures {
+        self.runtime_features
+    }
+
+    /// Take the runtime import statements added so far, so they're not inserted when the program
+    /// is exited, e.g. to put them somewhere else than after the existing imports.
+    ///
+    /// Imports added by other plugins sharing the same context are left to be inserted,
+    /// unless they are from the same source as a runtime import.
+    pub fn take_imports(&mut self) -> Vec<'a, Statement<'a>> {
+        self.ctx.module_imports.take_import_statements_from(&self.import_sources)
+    }
+
+    /// The runtime import statements which will be inserted when the program is exited,
+    /// e.g. to assert which imports were added without printing the program.
+    ///
+    /// The imports are kept as names until they are inserted, so this builds the statements
+    /// on each call rather than returning a slice of them.
+    pub fn pending_imports(&self) -> Vec<'a, Statement<'a>> {
+        self.ctx.module_imports.pending_import_statements_from(&self.import_sources)
+    }
+
     fn is_script(&self) -> bool {
         self.ctx.source_type.is_script()
     }
@@ -110,12 +229,27 @@ impl<'a> ReactJsx<'a> {
 
 // Add imports
 impl<'a> ReactJsx<'a> {
-    pub fn add_runtime_imports(&mut self, program: &mut Program<'a>) {
+    fn add_runtime_imports(&mut self, program: &mut Program<'a>) {
         if self.options.runtime.is_classic() {
             if self.options.import_source != "react" {
                 self.ctx.error(diagnostics::import_source_cannot_be_set());
             }
 
+            // e.g. `@jsxRuntime classic` in a file which imports `react/jsx-runtime`
+            if self.elements_count + self.fragments_count > 0 {
+                for stmt in &program.body {
+                    if let Statement::ImportDeclaration(decl) = stmt {
+                        let source = decl.source.value.as_str();
+                        if source.ends_with("/jsx-runtime") || source.ends_with("/jsx-dev-runtime")
+                        {
+                            self.ctx.error(diagnostics::classic_runtime_with_runtime_import(
+                                decl.source.span,
+                            ));
+                        }
+                    }
+                }
+            }
+
             if self.options.is_jsx_source_plugin_enabled() {
                 program.body.insert(0, self.jsx_source.get_var_file_name_statement());
             }
@@ -130,7 +264,9 @@ impl<'a> ReactJsx<'a> {
             return;
         }
 
-        let imports = self.ctx.module_imports.get_import_statements();
+        // Runtime imports go first, followed by the imports added by other plugins
+        let mut imports = self.take_imports();
+        imports.extend(self.ctx.module_imports.get_import_statements());
         let mut index = program
             .body
             .iter()
@@ -158,12 +294,24 @@ impl<'a> ReactJsx<'a> {
         if self.options.runtime.is_classic() {
             return;
         }
+        self.runtime_features |= self.runtime_features_of(e, has_key_after_props_spread, need_jsxs);
+        if !self.options.inject_imports {
+            return;
+        }
         match e {
             JSXElementOrFragment::Element(_) if has_key_after_props_spread => {
                 self.add_import_create_element();
             }
             JSXElementOrFragment::Element(_) if need_jsxs => self.add_import_jsxs(),
             JSXElementOrFragment::Element(_) => self.add_import_jsx(),
+            // Compiled to a host element, see `ReactOptions::fragment_host_tag`
+            JSXElementOrFragment::Fragment(_) if self.options.fragment_host_tag.is_some() => {
+                if need_jsxs {
+                    self.add_import_jsxs();
+                } else {
+                    self.add_import_jsx();
+                }
+            }
             JSXElementOrFragment::Fragment(_) => {
                 self.add_import_fragment();
                 if need_jsxs {
@@ -173,6 +321,32 @@ impl<'a> ReactJsx<'a> {
         }
     }
 
+    /// The runtime functions called for `e`, the same as the callees of [`Self::get_create_element`]
+    /// and [`Self::get_fragment`].
+    fn runtime_features_of<'b>(
+        &self,
+        e: &JSXElementOrFragment<'a, 'b>,
+        has_key_after_props_spread: bool,
+        need_jsxs: bool,
+    ) -> RuntimeFeatures {
+        let call = if self.options.development {
+            RuntimeFeatures::JSX_DEV
+        } else if need_jsxs {
+            RuntimeFeatures::JSXS
+        } else {
+            RuntimeFeatures::JSX
+        };
+        match e {
+            JSXElementOrFragment::Element(_) if has_key_after_props_spread => {
+                RuntimeFeatures::CREATE_ELEMENT
+            }
+            JSXElementOrFragment::Fragment(_) if self.options.fragment_host_tag.is_none() => {
+                call | RuntimeFeatures::FRAGMENT
+            }
+            _ => call,
+        }
+    }
+
     fn add_require_jsx_runtime(&mut self) {
         if !self.require_jsx_runtime {
             self.require_jsx_runtime = true;
@@ -189,7 +363,7 @@ impl<'a> ReactJsx<'a> {
             self.add_import_jsx_dev();
         } else if !self.import_jsx {
             self.import_jsx = true;
-            self.add_import_statement("jsx", "_jsx", self.jsx_runtime_importer.clone());
+            self.add_import_statement("jsx", self.jsx_runtime_importer.clone());
         }
     }
 
@@ -200,7 +374,7 @@ impl<'a> ReactJsx<'a> {
             self.add_import_jsx_dev();
         } else if !self.import_jsxs {
             self.import_jsxs = true;
-            self.add_import_statement("jsxs", "_jsxs", self.jsx_runtime_importer.clone());
+            self.add_import_statement("jsxs", self.jsx_runtime_importer.clone());
         }
     }
 
@@ -209,7 +383,7 @@ impl<'a> ReactJsx<'a> {
             self.add_require_jsx_runtime();
         } else if !self.import_jsx {
             self.import_jsx = true;
-            self.add_import_statement("jsxDEV", "_jsxDEV", self.jsx_runtime_importer.clone());
+            self.add_import_statement("jsxDEV", self.jsx_runtime_importer.clone());
         }
     }
 
@@ -218,7 +392,7 @@ impl<'a> ReactJsx<'a> {
             self.add_require_jsx_runtime();
         } else if !self.import_fragment {
             self.import_fragment = true;
-            self.add_import_statement("Fragment", "_Fragment", self.jsx_runtime_importer.clone());
+            self.add_import_statement("Fragment", self.jsx_runtime_importer.clone());
             self.add_import_jsx();
         }
     }
@@ -230,20 +404,38 @@ impl<'a> ReactJsx<'a> {
             if self.is_script() {
                 self.add_require_statement("_react", source.into(), true);
             } else {
-                self.add_import_statement("createElement", "_createElement", source.into());
+                self.add_import_statement("createElement", source.into());
             }
         }
     }
 
-    fn add_import_statement(&mut self, imported: &str, local: &str, source: CompactStr) {
-        let import = NamedImport::new(imported.into(), Some(local.into()));
+    fn add_import_statement(&mut self, imported: &str, source: CompactStr) {
+        let local = self.import_local_name(imported);
+        let import = NamedImport::new(imported.into(), Some(local.as_str().into()));
+        self.add_import_source(&source);
         self.ctx.module_imports.add_import(source, import);
     }
 
+    /// Local name of the runtime import `imported`, e.g. `_jsx` for `jsx`,
+    /// see [`ReactOptions::import_local_names`].
+    fn import_local_name(&self, imported: &str) -> Atom<'a> {
+        match self.options.import_local_names.get(imported) {
+            Some(local) => self.ast().new_atom(local),
+            None => self.ast().new_atom(&format!("_{imported}")),
+        }
+    }
+
     fn add_require_statement(&mut self, variable_name: &str, source: CompactStr, front: bool) {
         let import = NamedImport::new(variable_name.into(), None);
+        self.add_import_source(&source);
         self.ctx.module_imports.add_require(source, import, front);
     }
+
+    fn add_import_source(&mut self, source: &CompactStr) {
+        if !self.import_sources.contains(source) {
+            self.import_sources.push(source.clone());
+        }
+    }
 }
 
 enum JSXElementOrFragment<'a, 'b> {
@@ -293,6 +485,17 @@ impl<'a, 'b> JSXElementOrFragment<'a, 'b> {
         }
         false
     }
+
+    /// Whether the element has the `runtimeAttribute` with the value `"classic"`,
+    /// see [`ReactOptions::runtime_attribute`].
+    fn has_classic_runtime_attribute(&self, runtime_attribute: Option<&str>) -> bool {
+        let (Self::Element(e), Some(name)) = (self, runtime_attribute) else { return false };
+        e.opening_element.attributes.iter().any(|attr| {
+            matches!(attr, JSXAttributeItem::Attribute(attr)
+                if attr.is_identifier(name)
+                    && matches!(&attr.value, Some(JSXAttributeValue::StringLiteral(s)) if s.value == "classic"))
+        })
+    }
 }
 
 // Transform jsx
@@ -316,13 +519,51 @@ impl<'a> ReactJsx<'a> {
     /// ### Fragment
     /// React.createElement(React.Fragment, null, ...children)
     ///
+    /// ## Props order
+    /// The properties of the props object are always in this order, so the output is deterministic:
+    /// 1. Attributes and spreads in source order, including `ref`. In the automatic runtime,
+    ///    `key` is passed as an argument instead, unless it comes after a spread.
+    /// 2. Automatic: `children`.
+    ///    Classic: `__self`, then `__source`, when those plugins are enabled.
+    ///    In the automatic runtime they are passed as arguments instead.
+    ///
+    /// [`ReactOptions::dedupe_props`] removes properties without moving the remaining ones.
     fn transform_jsx<'b>(
         &mut self,
         e: &JSXElementOrFragment<'a, 'b>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
+        if let (JSXElementOrFragment::Element(e), Some(should_transform)) =
+            (e, self.options.should_transform)
+        {
+            if !should_transform(&e.opening_element.name) {
+                return Expression::JSXElement(self.ast().alloc(self.ast().copy(*e)));
+            }
+        }
+
         let is_fragment = e.is_fragment();
-        let has_key_after_props_spread = e.has_key_after_props_spread();
+        if is_fragment {
+            self.fragments_count += 1;
+        } else {
+            self.elements_count += 1;
+        }
+
+        if self.options.jsx_to_string {
+            if let Some(html) = self.transform_static_jsx_to_string(e, ctx) {
+                return html;
+            }
+        }
+
+        if let JSXElementOrFragment::Fragment(e) = e {
+            if self.is_fragment_as_array() {
+                return self.transform_fragment_to_array(e, ctx);
+            }
+        }
+
+        let options = Rc::clone(&self.options);
+        let runtime_attribute = options.runtime_attribute.as_deref();
+        let has_key_after_props_spread =
+            e.has_key_after_props_spread() || e.has_classic_runtime_attribute(runtime_attribute);
         // If has_key_after_props_spread is true, we need to fallback to `createElement` same behavior as classic runtime
         let is_classic = self.options.runtime.is_classic() || has_key_after_props_spread;
         let is_automatic = !is_classic;
@@ -351,7 +592,9 @@ impl<'a> ReactJsx<'a> {
         if let Some(attributes) = attributes {
             for attribute in attributes {
                 match attribute {
-                    // optimize `{...prop}` to `prop` in static mode
+                    // optimize `{...prop}` to `prop` in static mode.
+                    // Like Babel, the automatic runtime always gets a new object, e.g. `_jsx("div", { ...prop })`,
+                    // since `children` is added to the props.
                     JSXAttributeItem::SpreadAttribute(spread)
                         if is_classic && attributes_len == 1 =>
                     {
@@ -362,6 +605,11 @@ impl<'a> ReactJsx<'a> {
                             continue;
                         }
                     }
+                    JSXAttributeItem::Attribute(attr)
+                        if runtime_attribute.is_some_and(|name| attr.is_identifier(name)) =>
+                    {
+                        continue;
+                    }
                     JSXAttributeItem::Attribute(attr) => {
                         if attr.is_identifier("__self") {
                             self_attr_span = Some(attr.name.span());
@@ -391,15 +639,12 @@ impl<'a> ReactJsx<'a> {
 
         let mut need_jsxs = false;
 
-        let children = e.children();
-
         // Append children to object properties in automatic mode
         if is_automatic {
             let allocator = self.ast().allocator;
-            let mut children = Vec::from_iter_in(
-                children.iter().filter_map(|child| self.transform_jsx_child(child, ctx)),
-                allocator,
-            );
+            let mut children = self.ast().new_vec();
+            self.transform_children(e.children(), &mut children, ctx);
+            self.fold_static_text(&mut children);
             let children_len = children.len();
             if children_len != 0 {
                 let value = if children_len == 1 {
@@ -438,7 +683,7 @@ impl<'a> ReactJsx<'a> {
                 if let Some(span) = source_attr_span {
                     self.jsx_source.report_error(span);
                 } else {
-                    let (line, column) = get_line_column(e.span().start, self.ctx.source_text);
+                    let (line, column) = self.jsx_source.get_line_column(e.span().start);
                     properties.push(
                         self.jsx_source.get_object_property_kind_for_jsx_plugin(line, column),
                     );
@@ -448,14 +693,33 @@ impl<'a> ReactJsx<'a> {
 
         self.add_import(e, has_key_after_props_spread, need_jsxs);
 
-        // If runtime is automatic that means we always to add `{ .. }` as the second argument even if it's empty
-        if is_automatic || !properties.is_empty() {
-            let object_expression = self.ast().object_expression(SPAN, properties, None);
+        if self.options.dedupe_props {
+            Self::dedupe_props(&mut properties);
+        }
+
+        // If runtime is automatic that means we always to add `{ .. }` as the second argument even if it's empty,
+        // unless `omitEmptyProps` is enabled and no arguments follow it
+        let omit_props = is_automatic
+            && self.options.omit_empty_props
+            && properties.is_empty()
+            && key_prop.is_none()
+            && !is_development;
+        if (is_automatic && !omit_props) || !properties.is_empty() {
+            let mut object_expression = self.ast().object_expression(SPAN, properties, None);
+            if let Expression::ObjectExpression(props) = &object_expression {
+                if self.should_hoist_props(props, ctx) {
+                    object_expression = self.hoist_to_top_level("props", object_expression, ctx);
+                }
+            }
             arguments.push(Argument::from(object_expression));
-        } else if arguments.len() == 1 {
-            // If not and second argument doesn't exist, we should add `null` as the second argument
-            let null_expr = self.ast().literal_null_expression(NullLiteral::new(SPAN));
-            arguments.push(Argument::from(null_expr));
+        } else if !is_automatic && arguments.len() == 1 {
+            // If not and second argument doesn't exist, we should add `null` as the second argument,
+            // or `undefined`, see `ReactOptions::empty_props_value`
+            let empty_props = match self.options.empty_props_value {
+                EmptyPropsValue::Null => self.ast().literal_null_expression(NullLiteral::new(SPAN)),
+                EmptyPropsValue::Undefined => self.ast().void_0(),
+            };
+            arguments.push(Argument::from(empty_props));
         }
 
         // Only jsx and jsxDev will have more than 2 arguments
@@ -468,11 +732,10 @@ impl<'a> ReactJsx<'a> {
             }
 
             // isStaticChildren
+            // `true` only when the children were emitted as an array, which is not the same as
+            // having more than one JSX child, because whitespace-only text is dropped.
             if is_development {
-                let literal = self
-                    .ctx
-                    .ast
-                    .boolean_literal(SPAN, if is_fragment { false } else { children.len() > 1 });
+                let literal = self.ctx.ast.boolean_literal(SPAN, need_jsxs);
                 arguments.push(Argument::from(self.ctx.ast.literal_boolean_expression(literal)));
             }
 
@@ -483,7 +746,7 @@ impl<'a> ReactJsx<'a> {
                     if let Some(span) = source_attr_span {
                         self.jsx_source.report_error(span);
                     } else {
-                        let (line, column) = get_line_column(e.span().start, self.ctx.source_text);
+                        let (line, column) = self.jsx_source.get_line_column(e.span().start);
                         let expr = self.jsx_source.get_source_object(line, column);
                         arguments.push(Argument::from(expr));
                     }
@@ -503,20 +766,84 @@ impl<'a> ReactJsx<'a> {
         } else {
             // React.createElement(type, arguments, ...children)
             //                                      ^^^^^^^^^^^
-            arguments.extend(
-                children
-                    .iter()
-                    .filter_map(|child| self.transform_jsx_child(child, ctx))
-                    .map(Argument::from),
-            );
+            let mut children = self.ast().new_vec();
+            self.transform_children(e.children(), &mut children, ctx);
+            self.fold_static_text(&mut children);
+            arguments.extend(children.into_iter().map(Argument::from));
         }
 
         let callee = self.get_create_element(has_key_after_props_spread, need_jsxs);
-        self.ast().call_expression(SPAN, callee, arguments, false, None)
+        let call = self.ast().call_expression(SPAN, callee, arguments, false, None);
+
+        // __track(_jsx(...))
+        let call = match &self.options.wrap_calls {
+            Some(wrapper) => {
+                let callee = self.get_call_expression_callee(wrapper);
+                let arguments = self.ast().new_vec_single(Argument::from(call));
+                self.ast().call_expression(SPAN, callee, arguments, false, None)
+            }
+            None => call,
+        };
+
+        // _jsx(...) as JSX.Element
+        match &self.options.annotate_jsx_element_type {
+            Some(type_name) if self.ctx.source_type.is_typescript() => {
+                let type_annotation = self.get_ts_type_reference(type_name);
+                self.ast().ts_as_expression(SPAN, call, type_annotation)
+            }
+            _ => call,
+        }
+    }
+
+    /// Whether fragments are compiled to arrays, see [`ReactOptions::fragment_as_array`].
+    fn is_fragment_as_array(&self) -> bool {
+        self.options.fragment_as_array
+            && self.options.fragment_host_tag.is_none()
+            && self.options.runtime.is_classic()
+            && self.options.pragma_frag == "React.Fragment"
+    }
+
+    /// `<>{a}{b}</>` -> `[a, b]`
+    fn transform_fragment_to_array(
+        &mut self,
+        e: &JSXFragment<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let allocator = self.ast().allocator;
+        let mut children = Vec::from_iter_in(
+            e.children.iter().filter_map(|child| self.transform_jsx_child(child, ctx)),
+            allocator,
+        );
+        self.fold_static_text(&mut children);
+        let elements =
+            Vec::from_iter_in(children.into_iter().map(ArrayExpressionElement::from), allocator);
+        self.ast().array_expression(SPAN, elements, None)
     }
 
-    fn transform_element_name(&self, name: &JSXElementName<'a>) -> Expression<'a> {
+    /// Remove properties which are overridden by a later property with the same static key,
+    /// see [`ReactOptions::dedupe_props`].
+    fn dedupe_props(properties: &mut Vec<'a, ObjectPropertyKind<'a>>) {
+        let mut seen = FxHashSet::default();
+        // Whether to keep each property, in reverse order
+        let keep = properties
+            .iter()
+            .rev()
+            .map(|property| match property {
+                ObjectPropertyKind::ObjectProperty(prop) => prop
+                    .key
+                    .static_name()
+                    .map_or(true, |name| seen.insert(name) || !prop.value.is_literal()),
+                ObjectPropertyKind::SpreadProperty(_) => true,
+            })
+            .collect::<std::vec::Vec<_>>();
+        let mut keep_iter = keep.iter().rev();
+        properties.retain(|_| keep_iter.next().copied().unwrap_or(true));
+    }
+
+    fn transform_element_name(&mut self, name: &JSXElementName<'a>) -> Expression<'a> {
         match name {
+            // Only an ASCII lowercase first letter makes a host element, like Babel's `isCompatTag`,
+            // so e.g. `<ω />` and `<é />` are component references
             JSXElementName::Identifier(ident) => {
                 if ident.name == "this" {
                     self.ast().this_expression(SPAN)
@@ -524,11 +851,14 @@ impl<'a> ReactJsx<'a> {
                     let string = StringLiteral::new(SPAN, ident.name.clone());
                     self.ast().literal_string_expression(string)
                 } else {
-                    let ident = IdentifierReference::new(SPAN, ident.name.clone());
+                    self.used_components.insert(ident.name.clone());
+                    // Keeps the span of the name, to point at the component it references
+                    let ident = IdentifierReference::new(ident.span, ident.name.clone());
                     self.ctx.ast.identifier_reference_expression(ident)
                 }
             }
             JSXElementName::MemberExpression(member_expr) => {
+                self.used_components.insert(self.ast().new_atom(&member_expr.to_string()));
                 self.transform_jsx_member_expression(member_expr)
             }
             JSXElementName::NamespacedName(name) => {
@@ -543,6 +873,10 @@ impl<'a> ReactJsx<'a> {
     }
 
     fn get_fragment(&self) -> Expression<'a> {
+        if let Some(tag) = &self.options.fragment_host_tag {
+            let literal = StringLiteral::new(SPAN, self.ast().new_atom(tag));
+            return self.ast().literal_string_expression(literal);
+        }
         match self.options.runtime {
             ReactJsxRuntime::Classic => {
                 if self.options.pragma_frag == "React.Fragment" {
@@ -562,7 +896,7 @@ impl<'a> ReactJsx<'a> {
                     };
                     self.get_static_member_expression(object_name, "Fragment")
                 } else {
-                    let ident = IdentifierReference::new(SPAN, "_Fragment".into());
+                    let ident = IdentifierReference::new(SPAN, self.import_local_name("Fragment"));
                     self.ast().identifier_reference_expression(ident)
                 }
             }
@@ -581,24 +915,14 @@ impl<'a> ReactJsx<'a> {
                 }
             }
             ReactJsxRuntime::Automatic => {
-                let name = if self.is_script() {
-                    if has_key_after_props_spread {
-                        "createElement"
-                    } else if self.options.development {
-                        "jsxDEV"
-                    } else if jsxs {
-                        "jsxs"
-                    } else {
-                        "jsx"
-                    }
-                } else if has_key_after_props_spread {
-                    "_createElement"
+                let name = if has_key_after_props_spread {
+                    "createElement"
                 } else if self.options.development {
-                    "_jsxDEV"
+                    "jsxDEV"
                 } else if jsxs {
-                    "_jsxs"
+                    "jsxs"
                 } else {
-                    "_jsx"
+                    "jsx"
                 };
                 if self.is_script() {
                     let object_ident_name = if has_key_after_props_spread {
@@ -610,14 +934,18 @@ impl<'a> ReactJsx<'a> {
                     };
                     self.get_static_member_expression(object_ident_name, name)
                 } else {
-                    let ident = IdentifierReference::new(SPAN, name.into());
+                    let ident = IdentifierReference::new(SPAN, self.import_local_name(name));
                     self.ast().identifier_reference_expression(ident)
                 }
             }
         }
     }
 
+    /// Get `React`, or the `factory` object if one is configured
     fn get_react_references(&self) -> Expression<'a> {
+        if let Some(factory) = &self.options.factory {
+            return self.get_call_expression_callee(factory);
+        }
         let ident = IdentifierReference::new(SPAN, "React".into());
         self.ast().identifier_reference_expression(ident)
     }
@@ -633,18 +961,32 @@ impl<'a> ReactJsx<'a> {
         self.ast().static_member_expression(SPAN, object, property, false)
     }
 
-    /// Get the callee from `pragma` and `pragmaFrag`
+    /// Get the callee from `pragma`, `pragmaFrag` and `factory`
     fn get_call_expression_callee(&self, literal_callee: &str) -> Expression<'a> {
         let mut callee = literal_callee.split('.');
         let member = callee.next().unwrap();
-        let property = callee.next();
-        property.map_or_else(
-            || {
-                let ident = IdentifierReference::new(SPAN, self.ast().new_atom(member));
-                self.ast().identifier_reference_expression(ident)
-            },
-            |property_name| self.get_static_member_expression(member, property_name),
-        )
+        let ident = IdentifierReference::new(SPAN, self.ast().new_atom(member));
+        let object = self.ast().identifier_reference_expression(ident);
+        callee.fold(object, |object, property_name| {
+            let property = IdentifierName::new(SPAN, self.ast().new_atom(property_name));
+            self.ast().static_member_expression(SPAN, object, property, false)
+        })
+    }
+
+    /// `JSX.Element` -> the type reference `JSX.Element`
+    fn get_ts_type_reference(&self, type_name: &str) -> TSType<'a> {
+        let mut names = type_name.split('.');
+        let ident = IdentifierReference::new(SPAN, self.ast().new_atom(names.next().unwrap()));
+        let type_name =
+            names.fold(TSTypeName::IdentifierReference(self.ast().alloc(ident)), |left, name| {
+                let right = IdentifierName::new(SPAN, self.ast().new_atom(name));
+                TSTypeName::QualifiedName(self.ast().alloc(TSQualifiedName {
+                    span: SPAN,
+                    left,
+                    right,
+                }))
+            });
+        self.ast().ts_type_reference(SPAN, type_name, None)
     }
 
     fn transform_jsx_member_expression(&self, expr: &JSXMemberExpression<'a>) -> Expression<'a> {
@@ -669,13 +1011,17 @@ impl<'a> ReactJsx<'a> {
         &mut self,
         properties: &mut Vec<'a, ObjectPropertyKind<'a>>,
         attribute: &JSXAttributeItem<'a>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
         match attribute {
             JSXAttributeItem::Attribute(attr) => {
                 let kind = PropertyKind::Init;
                 let key = self.get_attribute_name(&attr.name);
-                let value = self.transform_jsx_attribute_value(attr.value.as_ref(), ctx);
+                let value = if attr.value.is_none() {
+                    self.get_boolean_shorthand_value(&attr.name)
+                } else {
+                    self.transform_jsx_attribute_value(attr.value.as_ref(), ctx)
+                };
                 let object_property =
                     self.ast().object_property(SPAN, kind, key, value, None, false, false, false);
                 let object_property = ObjectPropertyKind::ObjectProperty(object_property);
@@ -698,12 +1044,14 @@ impl<'a> ReactJsx<'a> {
     fn transform_jsx_attribute_value(
         &mut self,
         value: Option<&JSXAttributeValue<'a>>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
         match value {
             Some(JSXAttributeValue::StringLiteral(s)) => {
-                let jsx_text = Self::decode_entities(s.value.as_str());
+                let mut jsx_text = ctx.take_scratch_string();
+                Self::decode_entities(s.value.as_str(), &mut jsx_text);
                 let literal = StringLiteral::new(s.span, self.ast().new_atom(&jsx_text));
+                ctx.recycle_scratch_string(jsx_text);
                 self.ast().literal_string_expression(literal)
             }
             Some(JSXAttributeValue::Element(e)) => {
@@ -722,16 +1070,60 @@ impl<'a> ReactJsx<'a> {
         }
     }
 
+    /// Push the transformed `children` of an element or fragment to `out`.
+    ///
+    /// With [`ReactOptions::flatten_static_fragments`], the children of fragments are pushed
+    /// in place of the fragments, e.g. `<div>a<>b<>c</></></div>` has children `["a", "b", "c"]`.
+    fn transform_children(
+        &mut self,
+        children: &Vec<'a, JSXChild<'a>>,
+        out: &mut Vec<'a, Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for child in children {
+            match child {
+                JSXChild::Fragment(e) if self.options.flatten_static_fragments => {
+                    self.fragments_count += 1;
+                    self.transform_children(&e.children, out, ctx);
+                }
+                child => out.extend(self.transform_jsx_child(child, ctx)),
+            }
+        }
+    }
+
+    /// Concatenate adjacent string literals in `children`, see [`ReactOptions::fold_static_text`].
+    fn fold_static_text(&self, children: &mut Vec<'a, Expression<'a>>) {
+        if !self.options.fold_static_text {
+            return;
+        }
+        let mut folded = self.ast().new_vec_with_capacity(children.len());
+        for child in children.drain(..) {
+            match (folded.last_mut(), child) {
+                (Some(Expression::StringLiteral(prev)), Expression::StringLiteral(next)) => {
+                    let value = format!("{}{}", prev.value, next.value);
+                    prev.value = self.ast().new_atom(&value);
+                }
+                (_, child) => folded.push(child),
+            }
+        }
+        *children = folded;
+    }
+
     fn transform_jsx_child(
         &mut self,
         child: &JSXChild<'a>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Option<Expression<'a>> {
         match child {
-            JSXChild::Text(text) => self.transform_jsx_text(text.value.as_str()),
+            JSXChild::Text(text) => self.transform_jsx_text(text.value.as_str(), ctx),
             JSXChild::ExpressionContainer(e) => match &e.expression {
                 e @ match_expression!(JSXExpression) => Some(self.ast().copy(e.to_expression())),
-                JSXExpression::EmptyExpression(_) => None,
+                JSXExpression::EmptyExpression(_) => {
+                    if self.options.strict_children {
+                        self.ctx.error(diagnostics::empty_expression_child_dropped(e.span));
+                    }
+                    None
+                }
             },
             JSXChild::Element(e) => {
                 Some(self.transform_jsx(&JSXElementOrFragment::Element(e), ctx))
@@ -749,8 +1141,11 @@ impl<'a> ReactJsx<'a> {
     fn get_attribute_name(&self, name: &JSXAttributeName<'a>) -> PropertyKey<'a> {
         match name {
             JSXAttributeName::Identifier(ident) => {
-                let name = ident.name.clone();
-                if ident.name.contains('-') {
+                let name = match self.options.attribute_names.get(ident.name.as_str()) {
+                    Some(name) => self.ast().new_atom(name),
+                    None => ident.name.clone(),
+                };
+                if name.contains('-') {
                     let expr = self.ast().literal_string_expression(StringLiteral::new(SPAN, name));
                     self.ast().property_key_expression(expr)
                 } else {
@@ -765,11 +1160,47 @@ impl<'a> ReactJsx<'a> {
         }
     }
 
-    fn transform_jsx_text(&self, text: &str) -> Option<Expression<'a>> {
-        Self::fixup_whitespace_and_decode_entities(text).map(|s| {
-            let s = StringLiteral::new(SPAN, self.ast().new_atom(&s));
-            self.ast().literal_string_expression(s)
-        })
+    /// Get the value of an attribute without a value, e.g. `disabled` in `<input disabled />`.
+    fn get_boolean_shorthand_value(&self, name: &JSXAttributeName<'a>) -> Expression<'a> {
+        let value = match self.options.boolean_shorthand_value {
+            BooleanShorthandValue::Bool => {
+                return self.ast().literal_boolean_expression(BooleanLiteral::new(SPAN, true));
+            }
+            BooleanShorthandValue::EmptyString => Atom::from(""),
+            BooleanShorthandValue::Name => match name {
+                JSXAttributeName::Identifier(ident) => ident.name.clone(),
+                JSXAttributeName::NamespacedName(name) => self.ast().new_atom(&name.to_string()),
+            },
+        };
+        self.ast().literal_string_expression(StringLiteral::new(SPAN, value))
+    }
+
+    fn transform_jsx_text(&self, text: &str, ctx: &mut TraverseCtx<'a>) -> Option<Expression<'a>> {
+        let mut cleaned = ctx.take_scratch_string();
+        let expr = self.clean_jsx_text(text, &mut cleaned).then(|| {
+            // String children are emitted as raw HTML when compiling to strings
+            let s = if self.options.jsx_to_string {
+                self.ast().new_atom(&escape_html(&cleaned, false))
+            } else {
+                self.ast().new_atom(&cleaned)
+            };
+            self.ast().literal_string_expression(StringLiteral::new(SPAN, s))
+        });
+        ctx.recycle_scratch_string(cleaned);
+        expr
+    }
+
+    /// Trim whitespace and decode entities in JSX text, pushing the result to `out`.
+    /// Returns `false` if nothing is left.
+    fn clean_jsx_text(&self, text: &str, out: &mut String) -> bool {
+        if self.options.preserve_whitespace {
+            Self::decode_entities(text, out);
+            !text.is_empty()
+        } else if self.options.babel_compat_whitespace {
+            Self::clean_jsx_element_literal_child(text, out)
+        } else {
+            Self::fixup_whitespace_and_decode_entities(text, out)
+        }
     }
 
     /// JSX trims whitespace at the end and beginning of lines, except that the
@@ -787,14 +1218,16 @@ impl<'a> ReactJsx<'a> {
     /// - Remove empty lines and join the rest with " ".
     ///
     /// <https://github.com/microsoft/TypeScript/blob/f0374ce2a9c465e27a15b7fa4a347e2bd9079450/src/compiler/transformers/jsx.ts#L557-L608>
-    fn fixup_whitespace_and_decode_entities(text: &str) -> Option<String> {
-        let mut acc: Option<String> = None;
+    ///
+    /// The result is pushed to `out`. Returns `false` if there are no lines left.
+    fn fixup_whitespace_and_decode_entities(text: &str, out: &mut String) -> bool {
+        let mut has_lines = false;
         let mut first_non_whitespace: Option<usize> = Some(0);
         let mut last_non_whitespace: Option<usize> = None;
         for (index, c) in text.char_indices() {
             if is_line_terminator(c) {
                 if let (Some(first), Some(last)) = (first_non_whitespace, last_non_whitespace) {
-                    acc = Some(Self::add_line_of_jsx_text(acc, &text[first..last]));
+                    Self::add_line_of_jsx_text(&mut has_lines, &text[first..last], out);
                 }
                 first_non_whitespace = None;
             } else if c != ' ' && !is_irregular_whitespace(c) {
@@ -805,26 +1238,67 @@ impl<'a> ReactJsx<'a> {
             }
         }
         if let Some(first) = first_non_whitespace {
-            Some(Self::add_line_of_jsx_text(acc, &text[first..]))
-        } else {
-            acc
+            Self::add_line_of_jsx_text(&mut has_lines, &text[first..], out);
         }
+        has_lines
     }
 
-    fn add_line_of_jsx_text(acc: Option<String>, trimmed_line: &str) -> String {
-        let decoded = Self::decode_entities(trimmed_line);
-        if let Some(acc) = acc {
-            format!("{acc} {decoded}")
-        } else {
-            decoded
+    /// Babel's version of [`Self::fixup_whitespace_and_decode_entities`].
+    ///
+    /// - Decode entities in the whole text.
+    /// - Split the text into lines, and replace tabs with spaces.
+    /// - Trim leading spaces of all lines but the first, and trailing spaces of all lines but the last.
+    /// - Remove empty lines and join the rest with " ".
+    ///
+    /// The result is pushed to `out`. Returns `false` if nothing is left.
+    ///
+    /// Unlike the TypeScript algorithm, other whitespace characters (e.g. `&nbsp;`) are not trimmed.
+    ///
+    /// <https://github.com/babel/babel/blob/v7.24.6/packages/babel-types/src/utils/react/cleanJSXElementLiteralChild.ts>
+    fn clean_jsx_element_literal_child(text: &str, out: &mut String) -> bool {
+        let mut decoded = String::new();
+        Self::decode_entities(text, &mut decoded);
+        // Babel splits on `\r\n`, `\n` and `\r`. Splitting `\r\n` into 2 line breaks
+        // only adds an empty line between them, which doesn't affect the result.
+        let lines = decoded.split(['\r', '\n']).collect::<std::vec::Vec<_>>();
+        let last_non_empty_line =
+            lines.iter().rposition(|line| line.contains(|c| c != ' ' && c != '\t')).unwrap_or(0);
+
+        let start = out.len();
+        for (index, line) in lines.iter().enumerate() {
+            let line = line.replace('\t', " ");
+            let mut trimmed_line = line.as_str();
+            if index != 0 {
+                trimmed_line = trimmed_line.trim_start_matches(' ');
+            }
+            if index != lines.len() - 1 {
+                trimmed_line = trimmed_line.trim_end_matches(' ');
+            }
+            if !trimmed_line.is_empty() {
+                out.push_str(trimmed_line);
+                if index != last_non_empty_line {
+                    out.push(' ');
+                }
+            }
         }
+        out.len() > start
+    }
+
+    /// Push a line to `out`, after a space if `has_lines` says there are others before it.
+    fn add_line_of_jsx_text(has_lines: &mut bool, trimmed_line: &str, out: &mut String) {
+        if *has_lines {
+            out.push(' ');
+        }
+        *has_lines = true;
+        Self::decode_entities(trimmed_line, out);
     }
 
     /// Replace entities like "&nbsp;", "&#123;", and "&#xDEADBEEF;" with the characters they encode.
     /// * See <https://en.wikipedia.org/wiki/List_of_XML_and_HTML_character_entity_references>
     /// Code adapted from <https://github.com/microsoft/TypeScript/blob/514f7e639a2a8466c075c766ee9857a30ed4e196/src/compiler/transformers/jsx.ts#L617C1-L635>
-    fn decode_entities(s: &str) -> String {
-        let mut buffer = vec![];
+    ///
+    /// The decoded text is pushed to `out`.
+    fn decode_entities(s: &str, out: &mut String) {
         let mut chars = s.char_indices();
         let mut prev = 0;
         while let Some((i, c)) = chars.next() {
@@ -839,19 +1313,14 @@ impl<'a> ReactJsx<'a> {
                 }
                 if let Some(end) = end {
                     let word = &s[start + 1..end];
-                    buffer.extend_from_slice(s[prev..start].as_bytes());
+                    out.push_str(&s[prev..start]);
                     prev = end + 1;
                     if let Some(c) = XML_ENTITIES.get(word) {
-                        buffer.extend_from_slice(c.to_string().as_bytes());
+                        out.push(*c);
                     }
                 }
             }
         }
-        buffer.extend_from_slice(s[prev..].as_bytes());
-        #[allow(unsafe_code)]
-        // SAFETY: The buffer is constructed from valid utf chars.
-        unsafe {
-            String::from_utf8_unchecked(buffer)
-        }
+        out.push_str(&s[prev..]);
     }
 }
@@ -0,0 +1,21 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// Functions of the automatic runtime called by the transformed JSX,
+    /// see [`super::ReactJsx::emitted_runtime_functions`].
+    ///
+    /// Calls in the classic runtime, e.g. `React.createElement`, are not runtime functions.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct RuntimeFeatures: u8 {
+        /// `_jsx`, for elements with at most one child.
+        const JSX            = 1 << 0;
+        /// `_jsxs`, for elements with static children in an array.
+        const JSXS           = 1 << 1;
+        /// `_jsxDEV`, for all elements in development mode.
+        const JSX_DEV        = 1 << 2;
+        /// `_Fragment`, for fragments.
+        const FRAGMENT       = 1 << 3;
+        /// `_createElement`, for elements with a `key` after a props spread.
+        const CREATE_ELEMENT = 1 << 4;
+    }
+}
@@ -0,0 +1,1014 @@
+use std::{path::Path, rc::Rc};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::*, Trivias};
+use oxc_diagnostics::Error;
+use oxc_span::{Atom, SourceType};
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+use rustc_hash::FxHashSet;
+
+use crate::{
+    context::TransformCtx,
+    helpers::generated_references::{GeneratedReference, GeneratedReferences},
+    TransformOptions,
+};
+
+use super::{ReactJsx, ReactOptions, RuntimeFeatures};
+
+/// Return value of [`transform_react_jsx`].
+pub struct ReactJsxReturn<'a> {
+    /// Number of JSX elements transformed, including nested ones.
+    pub elements: usize,
+
+    /// Number of JSX fragments transformed, including nested ones.
+    pub fragments: usize,
+
+    /// Names of the components instantiated, see [`ReactJsx::used_components`].
+    pub used_components: FxHashSet<Atom<'a>>,
+
+    /// Functions of the automatic runtime called, see [`ReactJsx::emitted_runtime_functions`].
+    pub runtime_features: RuntimeFeatures,
+
+    /// Identifier references created by the transform, e.g. `_jsx`, `React` or `Foo` for `<Foo />`,
+    /// which are not bound to any symbol yet.
+    pub generated_references: Vec<GeneratedReference<'a>>,
+
+    /// Errors collected during the transformation.
+    pub errors: Vec<Error>,
+}
+
+/// Run [plugin-transform-react-jsx](https://babeljs.io/docs/babel-plugin-transform-react-jsx)
+/// on its own.
+///
+/// This constructs a [`ReactJsx`], walks `program`, and inserts the runtime imports,
+/// without the rest of the [`crate::Transformer`] pipeline.
+///
+/// To transform a batch of files, call this once per file. Each call starts from a new
+/// `ReactJsx`, so the runtime imports, counts and pragmas of one file never leak into the next.
+/// To reuse one `ReactJsx` for the batch instead, see [`ReactJsx::begin_file`].
+pub fn transform_react_jsx<'a>(
+    allocator: &'a Allocator,
+    source_path: &Path,
+    source_type: SourceType,
+    source_text: &'a str,
+    trivias: &'a Trivias,
+    options: ReactOptions,
+    program: &mut Program<'a>,
+) -> ReactJsxReturn<'a> {
+    let mut traverser = StandaloneReactJsx {
+        jsx: ReactJsx::for_file(allocator, source_path, source_type, source_text, trivias, options),
+        generated_references: GeneratedReferences::default(),
+        hoisted_references: None,
+    };
+    traverse_mut(&mut traverser, program, source_text, source_type, allocator);
+
+    ReactJsxReturn {
+        elements: traverser.jsx.elements_count,
+        fragments: traverser.jsx.fragments_count,
+        used_components: traverser.jsx.used_components().clone(),
+        runtime_features: traverser.jsx.emitted_runtime_functions(),
+        generated_references: traverser.generated_references.into_references(),
+        errors: traverser.jsx.take_errors(),
+    }
+}
+
+impl<'a> ReactJsx<'a> {
+    /// Create the plugin for transforming one file on its own, outside [`crate::Transformer`].
+    ///
+    /// Call [`ReactJsx::transform_expression`] and [`ReactJsx::transform_expression_on_exit`]
+    /// from the `enter_expression` and `exit_expression` of a [`Traverse`] of your own, and
+    /// [`ReactJsx::transform_program_on_exit`] from its `exit_program` to insert the runtime imports.
+    /// [`transform_react_jsx`] does all of this.
+    ///
+    /// The pragmas in the file's comments, e.g. `@jsxRuntime classic`, override `options`.
+    pub fn for_file(
+        allocator: &'a Allocator,
+        source_path: &Path,
+        source_type: SourceType,
+        source_text: &'a str,
+        trivias: &'a Trivias,
+        options: ReactOptions,
+    ) -> Self {
+        let ctx = Rc::new(TransformCtx::new(
+            allocator,
+            source_path,
+            source_type,
+            source_text,
+            trivias,
+            &TransformOptions::default(),
+        ));
+
+        let mut options = options;
+        options.jsx_plugin = true;
+        let base_options = Rc::new(options.clone());
+        options.update_with_comments(&ctx);
+        Self { base_options, ..Self::new(&Rc::new(options), &ctx) }
+    }
+
+    /// Move on to transforming the next file of a batch, with the options given to
+    /// [`ReactJsx::for_file`] and the pragmas of the new file.
+    ///
+    /// Everything recorded for the previous file is reset, including its counts, used components
+    /// and errors, and the runtime imports which were never inserted.
+    pub fn begin_file(
+        &mut self,
+        source_path: &Path,
+        source_type: SourceType,
+        source_text: &'a str,
+        trivias: &'a Trivias,
+    ) {
+        self.end_file();
+        let options = (*self.base_options).clone();
+        let allocator = self.ctx.ast.allocator;
+        *self = Self::for_file(allocator, source_path, source_type, source_text, trivias, options);
+    }
+
+    /// Finish transforming the current file, dropping the runtime imports and hoisted elements
+    /// which were not inserted by [`ReactJsx::transform_program_on_exit`] or taken with
+    /// [`ReactJsx::take_imports`], so they can't end up in another file.
+    ///
+    /// The counts, used components and errors of the file are kept until
+    /// [`ReactJsx::begin_file`].
+    pub fn end_file(&mut self) {
+        self.take_imports();
+        self.import_sources.clear();
+        self.import_jsx = false;
+        self.import_jsxs = false;
+        self.import_fragment = false;
+        self.import_create_element = false;
+        self.require_jsx_runtime = false;
+        self.pending_hoist = None;
+        self.hoisted.clear();
+    }
+
+    /// Take the errors reported while transforming the file.
+    pub fn take_errors(&self) -> Vec<Error> {
+        self.ctx.take_errors()
+    }
+}
+
+struct StandaloneReactJsx<'a> {
+    jsx: ReactJsx<'a>,
+    generated_references: GeneratedReferences<'a>,
+    /// Number of references recorded before the element being hoisted was entered
+    hoisted_references: Option<usize>,
+}
+
+impl<'a> Traverse<'a> for StandaloneReactJsx<'a> {
+    fn exit_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.jsx.transform_program_on_exit(program);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.jsx.transform_expression(expr, ctx);
+        if self.jsx.pending_hoist == Some(0) {
+            self.hoisted_references = Some(self.generated_references.len());
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let is_hoisting = self.jsx.pending_hoist == Some(0);
+        self.jsx.transform_expression_on_exit(expr, ctx);
+        if !is_hoisting {
+            return;
+        }
+        // The references in the element now belong to its declaration at the top level, and the
+        // reference to the declaration which replaces it is not walked, as it is already exited
+        if let Some(index) = self.hoisted_references.take() {
+            self.generated_references.move_to_scope(index, ctx.scopes().root_scope_id());
+        }
+        if let Expression::Identifier(ident) = expr {
+            self.generated_references.record(ident, ctx);
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.generated_references.record(ident, ctx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::{ast::*, Trivias};
+    use oxc_codegen::{Codegen, CodegenOptions};
+    use oxc_parser::Parser;
+    use oxc_span::{Atom, SourceType};
+    use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+    use super::{transform_react_jsx, ReactJsx, ReactJsxReturn, ReactOptions, RuntimeFeatures};
+    use crate::{helpers::module_imports::NamedImport, react::jsx::ReactJsxRuntime};
+
+    /// Parse `source_text` as the file at `path`, run [`transform_react_jsx`] on it, and pass
+    /// the transformed program and the result to `f`.
+    fn transform<R>(
+        path: &str,
+        source_text: &str,
+        options: ReactOptions,
+        f: impl for<'a> FnOnce(&Program<'a>, ReactJsxReturn<'a>) -> R,
+    ) -> R {
+        let source_type = SourceType::from_path(path).unwrap();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        assert!(ret.errors.is_empty(), "{source_text}");
+        let mut program = ret.program;
+        let result = transform_react_jsx(
+            &allocator,
+            Path::new(path),
+            source_type,
+            source_text,
+            &ret.trivias,
+            options,
+            &mut program,
+        );
+        f(&program, result)
+    }
+
+    fn print<const MINIFY: bool>(program: &Program) -> String {
+        Codegen::<MINIFY>::new("", "", CodegenOptions::default()).build(program).source_text
+    }
+
+    /// Transform `source_text` with `options` and print it.
+    fn transform_and_print<const MINIFY: bool>(source_text: &str, options: ReactOptions) -> String {
+        transform("test.jsx", source_text, options, |program, result| {
+            assert!(result.errors.is_empty(), "{source_text}");
+            print::<MINIFY>(program)
+        })
+    }
+
+    /// A [`ReactJsx`] with the JSX plugin enabled, for a traverser which calls it directly.
+    fn new_react_jsx<'a>(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        trivias: &'a Trivias,
+    ) -> ReactJsx<'a> {
+        let source_type = SourceType::from_path("test.jsx").unwrap();
+        let path = Path::new("test.jsx");
+        ReactJsx::for_file(
+            allocator,
+            path,
+            source_type,
+            source_text,
+            trivias,
+            ReactOptions::default(),
+        )
+    }
+
+    /// Transform JSX without inserting the imports when the program is exited
+    struct TransformWithoutFlush<'a> {
+        jsx: ReactJsx<'a>,
+    }
+
+    impl<'a> Traverse<'a> for TransformWithoutFlush<'a> {
+        fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+            self.jsx.transform_expression(expr, ctx);
+        }
+    }
+
+    /// `import { local, .. } from "source"` of each import declaration in `statements`
+    fn import_names(statements: &[Statement]) -> Vec<(String, Vec<String>)> {
+        statements
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Statement::ImportDeclaration(decl) => Some(decl),
+                _ => None,
+            })
+            .map(|decl| {
+                let specifiers = decl
+                    .specifiers
+                    .iter()
+                    .flatten()
+                    .filter_map(|specifier| match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(s) => {
+                            Some(s.local.name.to_string())
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                (decl.source.value.to_string(), specifiers)
+            })
+            .collect()
+    }
+
+    /// Calls in the top level expression statements of `program`
+    fn top_level_calls<'p, 'a>(
+        program: &'p Program<'a>,
+    ) -> impl Iterator<Item = &'p CallExpression<'a>> {
+        program.body.iter().filter_map(|stmt| match stmt {
+            Statement::ExpressionStatement(stmt) => match &stmt.expression {
+                Expression::CallExpression(call) => Some(&**call),
+                _ => None,
+            },
+            _ => None,
+        })
+    }
+
+    fn sorted_names<'a>(names: impl Iterator<Item = Atom<'a>>) -> Vec<String> {
+        let mut names = names.map(|name| name.to_string()).collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn test_transform_react_jsx() {
+        let source_text = "const el = <div><Foo /><>text</></div>;";
+        let printed =
+            transform("test.jsx", source_text, ReactOptions::default(), |program, result| {
+                assert!(result.errors.is_empty());
+                assert_eq!(result.elements, 2);
+                assert_eq!(result.fragments, 1);
+                print::<false>(program)
+            });
+        let expected = "\
+import {jsx as _jsx,Fragment as _Fragment,jsxs as _jsxs} from 'react/jsx-runtime';
+const el = _jsxs('div', {
+\tchildren:[_jsx(Foo, {}), _jsx(_Fragment, {
+\t\tchildren:'text'
+\t})]
+});
+";
+        assert_eq!(printed, expected);
+    }
+
+    #[test]
+    fn test_presets() {
+        let print = |source_text: &str, options| transform_and_print::<false>(source_text, options);
+        let classic = "React.createElement('div', null);\n";
+        assert_eq!(print("<div />;", ReactOptions::classic()), classic);
+        assert_eq!(print("<div />;", ReactOptions::react_17_classic()), classic);
+        assert_eq!(
+            print("<div />;", ReactOptions::automatic()),
+            "import {jsx as _jsx} from 'react/jsx-runtime';\n_jsx('div', {});\n"
+        );
+        let printed = print("<div />;", ReactOptions::automatic_dev());
+        let expected = "\
+import {jsxDEV as _jsxDEV} from 'react/jsx-dev-runtime';
+_jsxDEV('div', {}, void 0, false, {
+\tfileName:_jsxFileName,
+\tlineNumber:1,
+\tcolumnNumber:1
+}, this);
+";
+        assert!(printed.starts_with("var _jsxFileName = '"), "{printed}");
+        assert!(printed.ends_with(&format!("test.jsx';\n{expected}")), "{printed}");
+        assert_eq!(
+            print("<ul><li>a</li><>b</></ul>;", ReactOptions::hyperscript("h")),
+            "h('ul', null, h('li', null, 'a'), 'b');\n"
+        );
+    }
+
+    #[test]
+    fn test_used_components() {
+        let source_text = "<div><Foo /><Bar.Baz /><a.b.C /><span /><Foo /></div>;";
+        let used_components =
+            transform("test.jsx", source_text, ReactOptions::default(), |_, result| {
+                sorted_names(result.used_components.into_iter())
+            });
+        assert_eq!(used_components, ["Bar.Baz", "Foo", "a.b.C"]);
+    }
+
+    #[test]
+    fn test_development_is_static_children() {
+        let source_text = "<div>\n  <span />\n</div>;\n<div>\n  <span />\n  <span />\n</div>;";
+        let is_static_children =
+            transform("test.jsx", source_text, ReactOptions::automatic_dev(), |program, _| {
+                top_level_calls(program)
+                    .map(|call| match &call.arguments[3] {
+                        Argument::BooleanLiteral(lit) => lit.value,
+                        _ => unreachable!(),
+                    })
+                    .collect::<Vec<_>>()
+            });
+        assert_eq!(is_static_children, [false, true]);
+    }
+
+    #[test]
+    fn test_development_runtime_import() {
+        let printed = transform_and_print::<false>("<><div /></>;", ReactOptions::automatic_dev());
+        // `jsxDEV` and `Fragment` are imported from the dev runtime in one statement
+        let imports = printed.lines().filter(|line| line.starts_with("import")).collect::<Vec<_>>();
+        assert_eq!(
+            imports,
+            ["import {jsxDEV as _jsxDEV,Fragment as _Fragment} from 'react/jsx-dev-runtime';"]
+        );
+    }
+
+    #[test]
+    fn test_namespaced_attribute_names() {
+        let print = |runtime| {
+            let source_text = r#"<svg xlink:href="x" />;"#;
+            let source_type = SourceType::default().with_module(true).with_jsx(true);
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            let mut program = ret.program;
+
+            let options = ReactOptions { runtime, ..ReactOptions::default() };
+            let result = transform_react_jsx(
+                &allocator,
+                Path::new("test.jsx"),
+                source_type,
+                source_text,
+                &ret.trivias,
+                options,
+                &mut program,
+            );
+            assert!(result.errors.is_empty());
+
+            // The key is a string literal, which can't be an identifier
+            let Statement::ExpressionStatement(stmt) = program.body.last().unwrap() else {
+                unreachable!()
+            };
+            let Expression::CallExpression(call) = &stmt.expression else { unreachable!() };
+            let Argument::ObjectExpression(props) = &call.arguments[1] else { unreachable!() };
+            let ObjectPropertyKind::ObjectProperty(prop) = &props.properties[0] else {
+                unreachable!()
+            };
+            assert!(
+                matches!(&prop.key, PropertyKey::StringLiteral(lit) if lit.value == "xlink:href")
+            );
+
+            Codegen::<true>::new("", source_text, CodegenOptions::default())
+                .build(&program)
+                .source_text
+        };
+
+        assert_eq!(
+            print(ReactJsxRuntime::Classic),
+            "React.createElement('svg',{'xlink:href':'x'});"
+        );
+        assert!(print(ReactJsxRuntime::Automatic).ends_with("_jsx('svg',{'xlink:href':'x'});"));
+    }
+
+    #[test]
+    fn test_static_children_callee() {
+        let source_text = "<div><a /><b /></div>;\n<div><a /></div>;";
+        let source_type = SourceType::default().with_module(true).with_jsx(true);
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let mut program = ret.program;
+
+        transform_react_jsx(
+            &allocator,
+            Path::new("test.jsx"),
+            source_type,
+            source_text,
+            &ret.trivias,
+            ReactOptions::default(),
+            &mut program,
+        );
+
+        // `(callee, whether children are an array)` of each top level call
+        let calls = program
+            .body
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Statement::ExpressionStatement(stmt) => match &stmt.expression {
+                    Expression::CallExpression(call) => Some(call),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .map(|call| {
+                let Expression::Identifier(callee) = &call.callee else { unreachable!() };
+                let Argument::ObjectExpression(props) = &call.arguments[1] else { unreachable!() };
+                let ObjectPropertyKind::ObjectProperty(children) = &props.properties[0] else {
+                    unreachable!()
+                };
+                (callee.name.as_str(), matches!(children.value, Expression::ArrayExpression(_)))
+            })
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(calls, [("_jsxs", true), ("_jsx", false)]);
+    }
+
+    #[test]
+    fn test_development_arguments() {
+        let source_text = "<div key=\"k\">\n  <a />\n  <b />\n</div>;";
+        // jsxDEV(type, props, key, isStaticChildren, source, self)
+        let expected = "\
+var _jsxFileName='<CWD>/test.jsx';\
+import {jsxDEV as _jsxDEV} from 'react/jsx-dev-runtime';\
+_jsxDEV('div',{children:[\
+_jsxDEV('a',{},void 0,false,{fileName:_jsxFileName,lineNumber:2,columnNumber:3},this),\
+_jsxDEV('b',{},void 0,false,{fileName:_jsxFileName,lineNumber:3,columnNumber:3},this)\
+]},'k',true,{fileName:_jsxFileName,lineNumber:1,columnNumber:1},this);";
+        assert_eq!(
+            transform_and_print::<true>(source_text, ReactOptions::automatic_dev()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_import_source() {
+        let import = |source_text: &str, options: ReactOptions| {
+            let source_type = SourceType::default().with_module(true).with_jsx(true);
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            let mut program = ret.program;
+
+            transform_react_jsx(
+                &allocator,
+                Path::new("test.jsx"),
+                source_type,
+                source_text,
+                &ret.trivias,
+                options,
+                &mut program,
+            );
+            let printed = Codegen::<true>::new("", source_text, CodegenOptions::default())
+                .build(&program)
+                .source_text;
+            printed.split(';').find(|stmt| stmt.starts_with("import")).unwrap().to_string()
+        };
+
+        let preact = || ReactOptions { import_source: "preact".into(), ..ReactOptions::default() };
+        assert_eq!(import("<div />", preact()), "import {jsx as _jsx} from 'preact/jsx-runtime'");
+        assert_eq!(
+            import("<div />", ReactOptions { development: true, ..preact() }),
+            "import {jsxDEV as _jsxDEV} from 'preact/jsx-dev-runtime'"
+        );
+        // Set by a comment
+        assert_eq!(
+            import("/** @jsxImportSource preact */\n<div />", ReactOptions::default()),
+            "import {jsx as _jsx} from 'preact/jsx-runtime'"
+        );
+    }
+
+    #[test]
+    fn test_annotate_jsx_element_type() {
+        let print = |path: &str, type_name: &str| {
+            let options = ReactOptions {
+                annotate_jsx_element_type: Some(type_name.to_string()),
+                ..ReactOptions::classic()
+            };
+            transform(path, "const el = <div><Foo /></div>;", options, |program, _| {
+                let options =
+                    CodegenOptions { enable_typescript: true, ..CodegenOptions::default() };
+                Codegen::<true>::new("", "", options).build(program).source_text
+            })
+        };
+
+        assert_eq!(
+            print("test.tsx", "JSX.Element"),
+            "const el=(React.createElement('div',null,(React.createElement(Foo,null) as JSX.Element)) as JSX.Element);"
+        );
+        assert_eq!(
+            print("test.tsx", "Element"),
+            "const el=(React.createElement('div',null,(React.createElement(Foo,null) as Element)) as Element);"
+        );
+        // Not annotated in JavaScript
+        assert_eq!(
+            print("test.jsx", "JSX.Element"),
+            "const el=React.createElement('div',null,React.createElement(Foo,null));"
+        );
+    }
+
+    #[test]
+    fn test_hoist_annotated_element() {
+        let options = ReactOptions {
+            hoist_constant_elements: true,
+            annotate_jsx_element_type: Some("JSX.Element".to_string()),
+            ..ReactOptions::default()
+        };
+        let printed =
+            transform("test.tsx", "function f() { return <div />; }", options, |program, _| {
+                let options =
+                    CodegenOptions { enable_typescript: true, ..CodegenOptions::default() };
+                Codegen::<true>::new("", "", options).build(program).source_text
+            });
+        assert_eq!(
+            printed,
+            "import {jsx as _jsx} from 'react/jsx-runtime';const _ref=(_jsx('div',{}) as JSX.Element);function f(){return _ref}"
+        );
+    }
+
+    #[test]
+    fn test_imports_per_file() {
+        let files = [("a.jsx", "<><div /></>;"), ("b.jsx", "<div />;"), ("c.jsx", "1;")];
+        let imports = files
+            .iter()
+            .map(|(path, source_text)| {
+                transform(path, source_text, ReactOptions::default(), |program, _| {
+                    import_names(&program.body)
+                })
+            })
+            .collect::<Vec<_>>();
+        let runtime = |names: &[&str]| {
+            vec![(
+                "react/jsx-runtime".to_string(),
+                names.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            )]
+        };
+        assert_eq!(imports, [runtime(&["_jsx", "_Fragment"]), runtime(&["_jsx"]), vec![]]);
+    }
+
+    #[test]
+    fn test_emitted_runtime_functions() {
+        let runtime_features = |source_text: &str, options: ReactOptions| {
+            transform("test.jsx", source_text, options, |_, result| result.runtime_features)
+        };
+
+        let source_text = "<div />; <><a /></>; <ul><li /><li /></ul>;";
+        assert_eq!(
+            runtime_features(source_text, ReactOptions::automatic_dev()),
+            RuntimeFeatures::JSX_DEV | RuntimeFeatures::FRAGMENT
+        );
+        assert_eq!(
+            runtime_features(source_text, ReactOptions::automatic()),
+            RuntimeFeatures::JSX | RuntimeFeatures::JSXS | RuntimeFeatures::FRAGMENT
+        );
+        assert_eq!(
+            runtime_features("<div {...props} key=\"a\" />;", ReactOptions::automatic()),
+            RuntimeFeatures::CREATE_ELEMENT
+        );
+        assert_eq!(
+            runtime_features(
+                source_text,
+                ReactOptions { inject_imports: false, ..ReactOptions::automatic() }
+            ),
+            RuntimeFeatures::JSX | RuntimeFeatures::JSXS | RuntimeFeatures::FRAGMENT
+        );
+        assert_eq!(
+            runtime_features(source_text, ReactOptions::classic()),
+            RuntimeFeatures::empty()
+        );
+    }
+
+    #[test]
+    fn test_line_column_base() {
+        let source_text = "a;\n  <div />;";
+        for (line_base, column_base, expected) in
+            [(1, 1, [2.0, 3.0]), (1, 0, [2.0, 2.0]), (0, 0, [1.0, 2.0])]
+        {
+            let options = ReactOptions { line_base, column_base, ..ReactOptions::automatic_dev() };
+            let numbers = transform("test.jsx", source_text, options, |program, _| {
+                // `_jsxDEV("div", {}, void 0, false, { fileName, lineNumber, columnNumber }, this)`
+                let call = top_level_calls(program).last().unwrap();
+                let Argument::ObjectExpression(source) = &call.arguments[4] else { unreachable!() };
+                source
+                    .properties
+                    .iter()
+                    .skip(1)
+                    .map(|property| match property {
+                        ObjectPropertyKind::ObjectProperty(property) => match &property.value {
+                            Expression::NumericLiteral(lit) => lit.value,
+                            _ => unreachable!(),
+                        },
+                        ObjectPropertyKind::SpreadProperty(_) => unreachable!(),
+                    })
+                    .collect::<Vec<_>>()
+            });
+            assert_eq!(numbers, expected);
+        }
+    }
+
+    #[test]
+    fn test_should_transform() {
+        fn is_host_element(name: &JSXElementName) -> bool {
+            let JSXElementName::Identifier(ident) = name else { return false };
+            ident.name.starts_with(|c: char| c.is_ascii_lowercase())
+        }
+
+        let source_text = "<div><Foo><span /></Foo><p /></div>;";
+        let options =
+            ReactOptions { should_transform: Some(is_host_element), ..ReactOptions::default() };
+        let printed = transform("test.jsx", source_text, options, |program, result| {
+            assert_eq!(result.elements, 2);
+            print::<false>(program)
+        });
+        let expected = "\
+import {jsx as _jsx,jsxs as _jsxs} from 'react/jsx-runtime';
+_jsxs('div', {
+\tchildren:[<Foo><span/></Foo>, _jsx('p', {})]
+});
+";
+        assert_eq!(printed, expected);
+    }
+
+    #[test]
+    fn test_typescript_positions() {
+        let source_text = "
+            const a = <A /> as any;
+            const b = <B /> satisfies Element;
+            const c = (<C />)!;
+            @dec(<D />) class E { @dec(<F />) method() {} }
+        ";
+        let used_components =
+            transform("test.tsx", source_text, ReactOptions::default(), |_, result| {
+                assert!(result.errors.is_empty());
+                assert_eq!(result.elements, 5);
+                sorted_names(result.used_components.into_iter())
+            });
+        assert_eq!(used_components, ["A", "B", "C", "D", "F"]);
+    }
+
+    #[test]
+    fn test_type_arguments() {
+        let source_text =
+            "const a = <Comp<string> prop={x} />;\nconst b = <Foo.Bar<A, B>>c</Foo.Bar>;";
+        let source_type =
+            SourceType::default().with_module(true).with_typescript(true).with_jsx(true);
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        assert!(ret.errors.is_empty());
+        let mut program = ret.program;
+
+        let result = transform_react_jsx(
+            &allocator,
+            Path::new("test.tsx"),
+            source_type,
+            source_text,
+            &ret.trivias,
+            ReactOptions::default(),
+            &mut program,
+        );
+        assert!(result.errors.is_empty());
+
+        let printed = Codegen::<false>::new("", source_text, CodegenOptions::default())
+            .build(&program)
+            .source_text;
+        let expected = "\
+import {jsx as _jsx} from 'react/jsx-runtime';
+const a = _jsx(Comp, {
+\tprop:x
+});
+const b = _jsx(Foo.Bar, {
+\tchildren:'c'
+});
+";
+        assert_eq!(printed, expected);
+    }
+
+    #[test]
+    fn test_typescript_type_positions() {
+        // Types can't contain JSX, so there are no type-only positions to skip
+        let source_type = SourceType::from_path("test.tsx").unwrap();
+        for source_text in ["let a: typeof <A />;", "type B = <B />;", "let c: Array<<C />>;"] {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            assert!(!ret.errors.is_empty(), "{source_text}");
+        }
+
+        // JSX in values inside type-related syntax is transformed
+        let source_text = "
+            const a = <T,>() => <A />;
+            enum B { C = (<C />).type }
+            const d = (<D /> as unknown) as Element;
+            const e = <E /> satisfies unknown as Element;
+        ";
+        let elements = transform("test.tsx", source_text, ReactOptions::default(), |_, result| {
+            assert!(result.errors.is_empty());
+            result.elements
+        });
+        assert_eq!(elements, 4);
+    }
+
+    #[test]
+    fn test_generated_references() {
+        // `(name, span, whether it is in the root scope)` of each generated reference
+        let generated_references = |source_text: &str, options: ReactOptions| {
+            let source_type = SourceType::from_path("test.jsx").unwrap();
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            let mut program = ret.program;
+            let result = transform_react_jsx(
+                &allocator,
+                Path::new("test.jsx"),
+                source_type,
+                source_text,
+                &ret.trivias,
+                options,
+                &mut program,
+            );
+            let root_scope_id = program.scope_id.get().unwrap();
+            result
+                .generated_references
+                .iter()
+                .map(|reference| {
+                    let span = reference.span.source_text(source_text).to_string();
+                    (reference.name.to_string(), span, reference.scope_id == root_scope_id)
+                })
+                .collect::<Vec<_>>()
+        };
+        let reference = |name: &str, span: &str, in_root_scope| {
+            (name.to_string(), span.to_string(), in_root_scope)
+        };
+
+        // `baz` is copied from the original program along with its `ReferenceId`
+        assert_eq!(
+            generated_references("const el = <Foo bar={baz}><></></Foo>;", ReactOptions::default()),
+            [
+                reference("_jsx", "", true),
+                reference("Foo", "Foo", true),
+                reference("_jsx", "", true),
+                reference("_Fragment", "", true),
+            ]
+        );
+
+        let source_text = "function f() { return <div><Foo /></div>; }";
+        assert_eq!(
+            generated_references(source_text, ReactOptions::default()),
+            [
+                reference("_jsx", "", false),
+                reference("_jsx", "", false),
+                reference("Foo", "Foo", false),
+            ]
+        );
+
+        // The hoisted element is declared at the top level, and replaced by a reference to it
+        let options = ReactOptions { hoist_constant_elements: true, ..ReactOptions::default() };
+        assert_eq!(
+            generated_references("function f() { return <div />; }", options),
+            [reference("_jsx", "", true), reference("_ref", "", false)]
+        );
+        let options = ReactOptions { hoist_constant_props: true, ..ReactOptions::default() };
+        assert_eq!(
+            generated_references("function f() { return <Foo a=\"1\" />; }", options),
+            [
+                reference("_jsx", "", false),
+                reference("Foo", "Foo", false),
+                reference("_props", "", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_props_order() {
+        let source_text = r#"<div ref={r} key="k" a="1" {...p} b>child</div>;"#;
+        let print = |runtime| {
+            let options = ReactOptions { runtime, ..ReactOptions::automatic_dev() };
+            transform_and_print::<false>(source_text, options)
+        };
+
+        // Attributes in source order, then `__self` and `__source`
+        let expected = "\
+var _jsxFileName = '<CWD>/test.jsx';
+React.createElement('div', {
+\tref:r,
+\tkey:'k',
+\ta:'1',
+\t...p,
+\tb:true,
+\t__self:this,
+\t__source:{
+\t\tfileName:_jsxFileName,
+\t\tlineNumber:1,
+\t\tcolumnNumber:1
+\t}
+}, 'child');
+";
+        assert_eq!(print(ReactJsxRuntime::Classic), expected);
+        // Attributes in source order without `key`, then `children`
+        let expected = "\
+var _jsxFileName = '<CWD>/test.jsx';
+import {jsxDEV as _jsxDEV} from 'react/jsx-dev-runtime';
+_jsxDEV('div', {
+\tref:r,
+\ta:'1',
+\t...p,
+\tb:true,
+\tchildren:'child'
+}, 'k', false, {
+\tfileName:_jsxFileName,
+\tlineNumber:1,
+\tcolumnNumber:1
+}, this);
+";
+        assert_eq!(print(ReactJsxRuntime::Automatic), expected);
+    }
+
+    #[test]
+    fn test_strict_children() {
+        let cases = [
+            ("<div>{}</div>", false, 0),
+            ("<div>{}</div>", true, 1),
+            ("<div>{/* comment */}</div>", true, 1),
+            ("<div>{...children}</div>", false, 1),
+            ("<div>{...children}</div>", true, 1),
+            ("<div>\n  a\n</div>", true, 0),
+        ];
+        for (source_text, strict_children, errors) in cases {
+            let options = ReactOptions { strict_children, ..ReactOptions::default() };
+            let result_errors =
+                transform("test.jsx", source_text, options, |_, result| result.errors.len());
+            assert_eq!(result_errors, errors, "{source_text}");
+        }
+    }
+
+    #[test]
+    fn test_classic_runtime_with_runtime_import() {
+        let cases = [
+            ("/** @jsxRuntime classic */\nimport { jsx } from 'react/jsx-runtime';\n<div />;", 1),
+            ("/** @jsxRuntime classic */\nimport { jsxDEV } from 'preact/jsx-dev-runtime';\n<></>;", 1),
+            ("/** @jsxRuntime classic */\nimport React from 'react';\n<div />;", 0),
+            // Nothing is compiled with the classic runtime
+            ("/** @jsxRuntime classic */\nimport { jsx } from 'react/jsx-runtime';\njsx('div');", 0),
+            // No conflict with the automatic runtime
+            ("import { jsx } from 'react/jsx-runtime';\n<div />;", 0),
+        ];
+        for (source_text, errors) in cases {
+            let result_errors =
+                transform("test.jsx", source_text, ReactOptions::default(), |_, result| {
+                    result.errors.len()
+                });
+            assert_eq!(result_errors, errors, "{source_text}");
+        }
+    }
+
+    #[test]
+    fn test_pending_imports() {
+        let source_text = "<><div /><div /></>;";
+        let source_type = SourceType::from_path("test.jsx").unwrap();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let mut program = ret.program;
+
+        let jsx = new_react_jsx(&allocator, source_text, &ret.trivias);
+        let mut traverser = TransformWithoutFlush { jsx };
+        traverse_mut(&mut traverser, &mut program, source_text, source_type, &allocator);
+
+        assert_eq!(
+            import_names(&traverser.jsx.pending_imports()),
+            [(
+                "react/jsx-runtime".to_string(),
+                vec!["_jsx".to_string(), "_Fragment".into(), "_jsxs".into()]
+            )]
+        );
+        // The imports are still pending
+        assert_eq!(traverser.jsx.pending_imports().len(), 1);
+    }
+
+    #[test]
+    fn test_transform_jsx_element() {
+        /// Transform the outermost elements, keeping them in the program
+        struct CollectElements<'a> {
+            jsx: ReactJsx<'a>,
+            transformed: Vec<Expression<'a>>,
+        }
+
+        impl<'a> Traverse<'a> for CollectElements<'a> {
+            fn enter_jsx_element(&mut self, e: &mut JSXElement<'a>, ctx: &mut TraverseCtx<'a>) {
+                if !ctx.in_jsx_element() {
+                    let expr = self.jsx.transform_jsx_element(e, ctx);
+                    self.transformed.push(expr);
+                }
+            }
+        }
+
+        let source_text = "<div><Foo /></div>;";
+        let source_type = SourceType::from_path("test.jsx").unwrap();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let mut program = ret.program;
+
+        let jsx = new_react_jsx(&allocator, source_text, &ret.trivias);
+        let mut traverser = CollectElements { jsx, transformed: vec![] };
+        traverse_mut(&mut traverser, &mut program, source_text, source_type, &allocator);
+
+        assert_eq!(traverser.transformed.len(), 1);
+        let Expression::CallExpression(call) = &traverser.transformed[0] else { unreachable!() };
+        assert!(call.callee.is_specific_id("_jsx"));
+        assert!(matches!(&call.arguments[0], Argument::StringLiteral(lit) if lit.value == "div"));
+        assert_eq!(traverser.jsx.elements_count, 2);
+
+        // The element in the program is left as is
+        let Statement::ExpressionStatement(stmt) = &program.body[0] else { unreachable!() };
+        assert!(matches!(stmt.expression, Expression::JSXElement(_)));
+    }
+
+    #[test]
+    fn test_take_imports() {
+        let source_text = "<div />;";
+        let source_type = SourceType::from_path("test.jsx").unwrap();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let mut program = ret.program;
+
+        let jsx = new_react_jsx(&allocator, source_text, &ret.trivias);
+        // An import added by another plugin
+        let import = NamedImport::new("helper".into(), Some("_helper".into()));
+        jsx.ctx.module_imports.add_import("@babel/runtime/helper".into(), import);
+        let mut traverser = TransformWithoutFlush { jsx };
+        traverse_mut(&mut traverser, &mut program, source_text, source_type, &allocator);
+
+        let imports = traverser.jsx.take_imports();
+        assert_eq!(
+            import_names(&imports),
+            [("react/jsx-runtime".to_string(), vec!["_jsx".to_string()])]
+        );
+        assert!(traverser.jsx.take_imports().is_empty());
+        assert!(traverser.jsx.pending_imports().is_empty());
+
+        // The other plugin's import is left to insert into the program
+        traverser.jsx.transform_program_on_exit(&mut program);
+        assert_eq!(
+            import_names(&program.body),
+            [("@babel/runtime/helper".to_string(), vec!["_helper".to_string()])]
+        );
+    }
+}
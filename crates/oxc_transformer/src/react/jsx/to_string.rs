@@ -0,0 +1,132 @@
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+use oxc_traverse::TraverseCtx;
+
+use super::{JSXElementOrFragment, ReactJsx};
+
+/// Elements which cannot have children, and have no closing tag.
+///
+/// <https://html.spec.whatwg.org/multipage/syntax.html#void-elements>
+const VOID_ELEMENTS: [&str; 13] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Compile static JSX to HTML strings, see [`super::ReactOptions::jsx_to_string`].
+impl<'a> ReactJsx<'a> {
+    /// Compile `e` to a string literal of HTML, if it and all its descendants are static.
+    ///
+    /// Static means:
+    /// - Elements are host elements (e.g. `div`, not `Foo` or `foo.bar`).
+    /// - Attributes are string literals or valueless, and are not `key`, `ref` or `children`.
+    /// - Children are text, empty expressions, or static elements and fragments.
+    pub(super) fn transform_static_jsx_to_string<'b>(
+        &self,
+        e: &JSXElementOrFragment<'a, 'b>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut html = String::new();
+        let is_static = match e {
+            JSXElementOrFragment::Element(e) => self.push_static_element(e, &mut html, ctx),
+            JSXElementOrFragment::Fragment(e) => {
+                self.push_static_children(&e.children, &mut html, ctx)
+            }
+        };
+        is_static.then(|| {
+            let literal = StringLiteral::new(SPAN, self.ast().new_atom(&html));
+            self.ast().literal_string_expression(literal)
+        })
+    }
+
+    /// Push HTML for `e` to `html`. Returns `false` if `e` is not static.
+    fn push_static_element(
+        &self,
+        e: &JSXElement<'a>,
+        html: &mut String,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let JSXElementName::Identifier(ident) = &e.opening_element.name else { return false };
+        let name = ident.name.as_str();
+        if !name.starts_with(|c: char| c.is_ascii_lowercase()) {
+            return false;
+        }
+
+        html.push('<');
+        html.push_str(name);
+        for attribute in &e.opening_element.attributes {
+            let JSXAttributeItem::Attribute(attr) = attribute else { return false };
+            let JSXAttributeName::Identifier(attr_name) = &attr.name else { return false };
+            let attr_name = match attr_name.name.as_str() {
+                "key" | "ref" | "children" => return false,
+                "className" => "class",
+                "htmlFor" => "for",
+                attr_name => attr_name,
+            };
+            html.push(' ');
+            html.push_str(attr_name);
+            match &attr.value {
+                None => {}
+                Some(JSXAttributeValue::StringLiteral(s)) => {
+                    let mut value = String::new();
+                    Self::decode_entities(s.value.as_str(), &mut value);
+                    html.push_str("=\"");
+                    html.push_str(&escape_html(&value, true));
+                    html.push('"');
+                }
+                Some(_) => return false,
+            }
+        }
+        html.push('>');
+
+        if VOID_ELEMENTS.contains(&name) {
+            return e.children.is_empty();
+        }
+        if !self.push_static_children(&e.children, html, ctx) {
+            return false;
+        }
+        html.push_str("</");
+        html.push_str(name);
+        html.push('>');
+        true
+    }
+
+    /// Push HTML for `children` to `html`. Returns `false` if any child is not static.
+    fn push_static_children(
+        &self,
+        children: &Vec<'a, JSXChild<'a>>,
+        html: &mut String,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        children.iter().all(|child| match child {
+            JSXChild::Text(text) => {
+                let mut cleaned = String::new();
+                if self.clean_jsx_text(text.value.as_str(), &mut cleaned) {
+                    html.push_str(&escape_html(&cleaned, false));
+                }
+                true
+            }
+            JSXChild::Element(e) => self.push_static_element(e, html, ctx),
+            JSXChild::Fragment(e) => self.push_static_children(&e.children, html, ctx),
+            JSXChild::ExpressionContainer(e) => {
+                matches!(e.expression, JSXExpression::EmptyExpression(_))
+            }
+            JSXChild::Spread(_) => false,
+        })
+    }
+}
+
+/// Escape `s` for use as HTML text, or as an attribute value in double quotes.
+pub(super) fn escape_html(s: &str, is_attribute: bool) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' if is_attribute => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
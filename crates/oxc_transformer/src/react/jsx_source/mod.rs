@@ -7,7 +7,7 @@ use oxc_syntax::number::NumberBase;
 
 use crate::context::Ctx;
 
-use super::utils::get_line_column;
+use super::{utils::get_line_column, ReactOptions};
 
 const SOURCE: &str = "__source";
 const FILE_NAME_VAR: &str = "_jsxFileName";
@@ -23,12 +23,20 @@ const FILE_NAME_VAR: &str = "_jsxFileName";
 ///
 /// TODO: get lineNumber and columnNumber from somewhere
 pub struct ReactJsxSource<'a> {
+    options: Rc<ReactOptions>,
     ctx: Ctx<'a>,
 }
 
 impl<'a> ReactJsxSource<'a> {
-    pub fn new(ctx: &Ctx<'a>) -> Self {
-        Self { ctx: Rc::clone(ctx) }
+    pub fn new(options: &Rc<ReactOptions>, ctx: &Ctx<'a>) -> Self {
+        Self { options: Rc::clone(options), ctx: Rc::clone(ctx) }
+    }
+
+    /// Get line and column of `offset`, numbered from [`ReactOptions::line_base`]
+    /// and [`ReactOptions::column_base`].
+    pub fn get_line_column(&self, offset: u32) -> (usize, usize) {
+        let ReactOptions { line_base, column_base, .. } = *self.options;
+        get_line_column(offset, self.ctx.source_text, line_base, column_base)
     }
 
     pub fn transform_jsx_opening_element(&mut self, elem: &mut JSXOpeningElement<'a>) {
@@ -73,7 +81,7 @@ impl<'a> ReactJsxSource<'a> {
         let key = JSXAttributeName::Identifier(
             self.ctx.ast.alloc(self.ctx.ast.jsx_identifier(SPAN, SOURCE.into())),
         );
-        let (line, column) = get_line_column(elem.span.start, self.ctx.source_text);
+        let (line, column) = self.get_line_column(elem.span.start);
         let object = self.get_source_object(line, column);
         let expr = self.ctx.ast.jsx_expression_container(SPAN, JSXExpression::from(object));
         let value = JSXAttributeValue::ExpressionContainer(expr);
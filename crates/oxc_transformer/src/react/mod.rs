@@ -12,7 +12,11 @@ use oxc_traverse::TraverseCtx;
 
 use crate::context::Ctx;
 
-pub use self::{display_name::ReactDisplayName, jsx::ReactJsx, options::ReactOptions};
+pub use self::{
+    display_name::ReactDisplayName,
+    jsx::{transform_react_jsx, ReactJsx, ReactJsxReturn, RuntimeFeatures},
+    options::ReactOptions,
+};
 
 /// [Preset React](https://babel.dev/docs/babel-preset-react)
 ///
@@ -52,19 +56,19 @@ impl<'a> React<'a> {
         }
     }
 
-    pub fn transform_expression(&mut self, expr: &mut Expression<'a>, ctx: &TraverseCtx<'a>) {
-        match expr {
-            Expression::JSXElement(e) => {
-                if self.options.is_jsx_plugin_enabled() {
-                    *expr = self.jsx.transform_jsx_element(e, ctx);
-                }
-            }
-            Expression::JSXFragment(e) => {
-                if self.options.is_jsx_plugin_enabled() {
-                    *expr = self.jsx.transform_jsx_fragment(e, ctx);
-                }
-            }
-            _ => {}
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.is_jsx_plugin_enabled() {
+            self.jsx.transform_expression(expr, ctx);
+        }
+    }
+
+    pub fn transform_expression_on_exit(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.is_jsx_plugin_enabled() {
+            self.jsx.transform_expression_on_exit(expr, ctx);
         }
     }
 
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use oxc_ast::ast::JSXElementName;
+use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
 use crate::Ctx;
@@ -45,6 +47,31 @@ impl ReactJsxRuntime {
     }
 }
 
+/// Decides the value of boolean shorthand attributes, e.g. `disabled` in `<input disabled />`.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BooleanShorthandValue {
+    /// `{ disabled: true }`
+    #[default]
+    Bool,
+    /// `{ disabled: "" }`
+    EmptyString,
+    /// `{ disabled: "disabled" }`
+    Name,
+}
+
+/// Decides the props argument of elements without attributes in the classic runtime,
+/// e.g. `<div />`.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EmptyPropsValue {
+    /// `React.createElement("div", null)`
+    #[default]
+    Null,
+    /// `React.createElement("div", void 0)`
+    Undefined,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct ReactOptions {
@@ -70,6 +97,17 @@ pub struct ReactOptions {
     /// Defaults to `false`.
     pub development: bool,
 
+    /// The number of the first line in `lineNumber` of `__source`, added in development mode.
+    ///
+    /// Defaults to `1`, like Babel.
+    pub line_base: usize,
+
+    /// The number of the first column in `columnNumber` of `__source`, added in development mode.
+    /// Columns are counted in characters, not bytes.
+    ///
+    /// Defaults to `1`, like Babel.
+    pub column_base: usize,
+
     /// Toggles whether or not to throw an error if a XML namespaced tag name is used.
     ///
     /// Though the JSX spec allows this, it is disabled by default since React's JSX does not currently have support for it.
@@ -83,6 +121,218 @@ pub struct ReactOptions {
     /// Defaults to `true`.
     #[serde(default = "default_as_true")]
     pub pure: bool,
+
+    /// Decides the value of boolean shorthand attributes, e.g. `disabled` in `<input disabled />`.
+    ///
+    /// Defaults to `bool`, which compiles to `{ disabled: true }`.
+    pub boolean_shorthand_value: BooleanShorthandValue,
+
+    /// Compile JSX elements and fragments without dynamic parts to HTML strings,
+    /// for server rendering runtimes which output string children as raw HTML.
+    ///
+    /// e.g. `<p class="a">Hi</p>` compiles to `'<p class="a">Hi</p>'`.
+    /// Elements with dynamic parts still compile to `_jsx` calls, with static children as strings,
+    /// and text children escaped.
+    ///
+    /// This is experimental.
+    ///
+    /// Defaults to `false`.
+    pub jsx_to_string: bool,
+
+    /// Clean up whitespace in JSX text with Babel's `cleanJSXElementLiteralChild` algorithm,
+    /// instead of the TypeScript-derived one, so that the output matches Babel's exactly.
+    ///
+    /// Babel decodes entities before trimming, replaces tabs with spaces,
+    /// and only trims spaces and tabs around line breaks.
+    ///
+    /// Defaults to `false`.
+    pub babel_compat_whitespace: bool,
+
+    /// Keep the whitespace in JSX text exactly as written, instead of trimming and collapsing it,
+    /// e.g. `<div>\n  a  b\n</div>` has the child `"\n  a  b\n"`, for tools which round-trip JSX.
+    ///
+    /// Text which is only whitespace is kept too. Entities are still decoded.
+    /// Takes precedence over [`ReactOptions::babel_compat_whitespace`].
+    ///
+    /// Defaults to `false`.
+    pub preserve_whitespace: bool,
+
+    /// Wrap each compiled element and fragment call in a call to this function,
+    /// e.g. `__track` compiles `<div />` to `__track(_jsx("div", {}))`.
+    ///
+    /// It should be a qualified name (e.g. `profiler.track`) or an identifier (e.g. `__track`),
+    /// and is useful for instrumenting renders in profiling builds.
+    ///
+    /// Defaults to `None`.
+    pub wrap_calls: Option<String>,
+
+    /// Assert the type of each compiled element and fragment call in TypeScript files,
+    /// for output which is type checked afterwards,
+    /// e.g. `JSX.Element` compiles `<div />` to `_jsx("div", {}) as JSX.Element`.
+    ///
+    /// It should be a qualified name (e.g. `React.JSX.Element`) or an identifier.
+    /// It is ignored in JavaScript files, and for fragments compiled to arrays and elements
+    /// compiled to strings.
+    ///
+    /// Defaults to `None`.
+    pub annotate_jsx_element_type: Option<String>,
+
+    /// Remove props which are overridden by a later prop with the same name,
+    /// e.g. `<div a="1" a="2" />` compiles to `{ a: "2" }` instead of `{ a: "1", a: "2" }`.
+    ///
+    /// Only props with literal values are removed, so that no side effects are dropped.
+    /// The later prop is kept in its own position.
+    ///
+    /// Defaults to `false`.
+    pub dedupe_props: bool,
+
+    /// Rename attributes when compiling them to props,
+    /// for runtimes which expect HTML attribute names rather than React ones.
+    ///
+    /// e.g. `{ "className": "class", "htmlFor": "for" }` compiles `<label className="a" htmlFor="b" />`
+    /// to `{ class: "a", for: "b" }`.
+    ///
+    /// Defaults to no renaming.
+    pub attribute_names: FxHashMap<String, String>,
+
+    /// Merge the named imports from the JSX runtime and from `importSource` into one statement
+    /// per source once the program is transformed, including imports which were already in it,
+    /// e.g. when modules were concatenated before being transformed.
+    ///
+    /// Duplicate specifiers are removed, so `import { jsx as _jsx } from "react/jsx-runtime"`
+    /// in the source and the same import added by this plugin become a single import.
+    ///
+    /// Defaults to `false`.
+    pub merge_runtime_imports: bool,
+
+    /// Rename the local bindings of the runtime imports in the automatic runtime,
+    /// to make them easier to recognise when debugging bundled output.
+    ///
+    /// e.g. `{ "jsx": "__oxcJsx" }` compiles `<div />` to
+    /// `import { jsx as __oxcJsx } from "react/jsx-runtime"; __oxcJsx("div", {});`.
+    ///
+    /// Keys are the imported names: `jsx`, `jsxs`, `jsxDEV`, `Fragment` and `createElement`.
+    /// Imports which aren't renamed use their name prefixed with `_`, e.g. `_jsxs`.
+    /// Scripts use `require` and are not affected.
+    ///
+    /// Defaults to no renaming.
+    pub import_local_names: FxHashMap<String, String>,
+
+    /// Omit the props argument of elements without attributes or children in the automatic runtime,
+    /// for runtimes which accept it, e.g. `<div />` compiles to `_jsx("div")` instead of `_jsx("div", {})`.
+    ///
+    /// The props are still passed if there is a `key`, or in development mode,
+    /// as more arguments follow them.
+    ///
+    /// Defaults to `false`, as React expects props.
+    pub omit_empty_props: bool,
+
+    /// Decides the props argument of elements without attributes in the classic runtime,
+    /// for custom factories which expect `undefined` rather than `null`.
+    /// Children are still passed after it, e.g. `<div>a</div>` compiles to
+    /// `React.createElement("div", void 0, "a")` with `undefined`.
+    ///
+    /// Defaults to `null`, like React.
+    pub empty_props_value: EmptyPropsValue,
+
+    /// Hoist elements which are the same on every render out of functions, to `const`s at the
+    /// top level of the module, like Babel's `@babel/plugin-transform-react-constant-elements`,
+    /// e.g. `<div className="x" />` inside a component compiles to a `const _ref = _jsx("div", { className: "x" })`.
+    ///
+    /// Only whole trees of host elements are hoisted, with literal attributes other than `ref`,
+    /// and children which are text or, recursively, such elements and fragments.
+    /// Nothing is hoisted in development mode, as elements reference `this` through `__self`.
+    ///
+    /// Defaults to `false`.
+    pub hoist_constant_elements: bool,
+
+    /// Hoist props objects whose values are all literals out of functions, to `const`s at the
+    /// top level of the module, so they aren't allocated on every render,
+    /// e.g. `<div className="x" />` inside a component compiles to `_jsx("div", _props)`
+    /// with `const _props = { className: "x" }`.
+    ///
+    /// Props with values which can change, e.g. `<div id={id} />` or children elements,
+    /// are not hoisted.
+    ///
+    /// Defaults to `false`.
+    pub hoist_constant_props: bool,
+
+    /// Inline the children of fragments nested in elements and fragments into the parent's
+    /// children, instead of creating fragments at runtime, e.g. `<div>a<>b<c /></></div>`
+    /// compiles to `_jsxs("div", { children: ["a", "b", _jsx("c", {})] })` in the automatic runtime
+    /// and `React.createElement("div", null, "a", "b", React.createElement("c", null))` in the classic runtime.
+    ///
+    /// Fragments with a `key` are written `<Fragment key={..}>`, which is an element, so they
+    /// are never flattened. Fragments which aren't children of another element are not affected.
+    ///
+    /// Defaults to `false`.
+    pub flatten_static_fragments: bool,
+
+    /// Concatenate adjacent static string children, i.e. text and string literals in expression
+    /// containers, into one string, e.g. `<p>Hello{" "}World</p>` compiles to
+    /// `_jsx("p", { children: "Hello World" })` instead of passing three children.
+    ///
+    /// Strings are never folded across other children, e.g. `<p>a{b}c</p>` keeps three children.
+    ///
+    /// Defaults to `false`.
+    pub fold_static_text: bool,
+
+    /// Report an error for children which are dropped from the output, instead of dropping
+    /// them silently, e.g. to catch lost comments in `<div>{/* comment */}</div>` in CI.
+    ///
+    /// Empty expressions such as `{}` and `{/* comment */}` are reported in this mode.
+    /// Spread children such as `{...children}` are always reported, as React doesn't support them.
+    /// Whitespace-only text containing a line break is not reported, as JSX ignores it.
+    ///
+    /// Defaults to `false`.
+    pub strict_children: bool,
+
+    /// Compile fragments to arrays of their children in the classic runtime,
+    /// e.g. `<><a /><b /></>` to `[React.createElement("a", null), React.createElement("b", null)]`.
+    /// Note that React warns about elements without a `key` in arrays.
+    ///
+    /// Fragments are compiled, from highest precedence:
+    /// 1. To host elements, with [`ReactOptions::fragment_host_tag`].
+    /// 2. With [`ReactOptions::pragma_frag`], if it's not `React.Fragment`.
+    /// 3. To arrays, if this is `true`.
+    /// 4. With `React.Fragment`, or `Fragment` of the [`ReactOptions::factory`] object.
+    ///
+    /// This has no effect in the automatic runtime, where fragments use `Fragment`
+    /// from the runtime unless [`ReactOptions::fragment_host_tag`] is set.
+    ///
+    /// Defaults to `false`.
+    pub fragment_as_array: bool,
+
+    /// Compile fragments to host elements with this tag in both runtimes, for renderers which
+    /// don't support fragments, e.g. `"template"` compiles `<><a /></>` to
+    /// `_jsx("template", { children: _jsx("a", {}) })`.
+    ///
+    /// Takes precedence over [`ReactOptions::pragma_frag`] and [`ReactOptions::fragment_as_array`].
+    ///
+    /// Defaults to `None`, which compiles fragments with the fragment component.
+    pub fragment_host_tag: Option<String>,
+
+    /// Name of an attribute which overrides the runtime of the element it is on,
+    /// e.g. `data-jsx-runtime` compiles `<div data-jsx-runtime="classic" />` to
+    /// `_createElement("div", null)` in an automatic runtime file.
+    ///
+    /// Only the value `"classic"` changes the output, by falling back to `createElement`
+    /// from `importSource`, as for a `key` after a spread. The attribute is removed from the props.
+    ///
+    /// Defaults to `None`.
+    pub runtime_attribute: Option<String>,
+
+    /// Only compile elements for which this returns `true`, e.g. to compile host elements
+    /// and leave components as JSX.
+    ///
+    /// Elements which are not compiled are left as they are, along with all their children,
+    /// and are not counted as transformed. Fragments are always compiled.
+    ///
+    /// This is experimental, and can only be set from Rust.
+    ///
+    /// Defaults to `None`, which compiles all elements.
+    #[serde(skip)]
+    pub should_transform: Option<fn(&JSXElementName<'_>) -> bool>,
     //
     // React Automatic Runtime
     //
@@ -91,6 +341,15 @@ pub struct ReactOptions {
     /// Defaults to `react`.
     #[serde(default = "default_for_import_source")]
     pub import_source: Cow<'static, str>,
+
+    /// Toggles whether or not to insert imports (or requires) of the runtime functions.
+    ///
+    /// When disabled, the compiled JSX still references `_jsx`, `_jsxs`, `_Fragment` etc.,
+    /// and the user is responsible for providing them, e.g. as globals.
+    ///
+    /// Defaults to `true`.
+    #[serde(default = "default_as_true")]
+    pub inject_imports: bool,
     //
     // React Classic Runtime
     //
@@ -106,10 +365,23 @@ pub struct ReactOptions {
 
     /// Replace the component used when compiling JSX fragments. It should be a valid JSX tag name.
     ///
+    /// Takes precedence over [`ReactOptions::fragment_as_array`] when set to anything
+    /// other than `React.Fragment`, including with a `@jsxFrag` comment.
+    ///
     /// Defaults to `React.Fragment`.
     #[serde(default = "default_for_pragma_frag")]
     pub pragma_frag: Cow<'static, str>,
 
+    /// Replace the `React` object which `createElement` and `Fragment` are read from.
+    ///
+    /// It should be an identifier or a dotted path (e.g. `jsx` or `ui.factory`),
+    /// so that JSX compiles to `jsx.createElement(...)` and fragments to `jsx.Fragment`.
+    /// Unlike `pragma`, this only replaces the object, not the whole callee.
+    /// An explicitly set `pragma` or `pragmaFrag` takes precedence over it.
+    ///
+    /// Defaults to `None`, which uses `React`.
+    pub factory: Option<String>,
+
     /// `useBuiltIns` is deprecated in Babel 8.
     ///
     /// This value is used to skip Babel tests, and is not used in oxc.
@@ -130,11 +402,36 @@ impl Default for ReactOptions {
             jsx_source_plugin: false,
             runtime: ReactJsxRuntime::default(),
             development: false,
+            line_base: 1,
+            column_base: 1,
             throw_if_namespace: default_as_true(),
             pure: default_as_true(),
+            boolean_shorthand_value: BooleanShorthandValue::default(),
+            jsx_to_string: false,
+            babel_compat_whitespace: false,
+            preserve_whitespace: false,
+            wrap_calls: None,
+            annotate_jsx_element_type: None,
+            dedupe_props: false,
+            attribute_names: FxHashMap::default(),
+            import_local_names: FxHashMap::default(),
+            merge_runtime_imports: false,
+            omit_empty_props: false,
+            empty_props_value: EmptyPropsValue::default(),
+            hoist_constant_elements: false,
+            hoist_constant_props: false,
+            flatten_static_fragments: false,
+            fold_static_text: false,
+            strict_children: false,
+            fragment_as_array: false,
+            fragment_host_tag: None,
+            runtime_attribute: None,
+            should_transform: None,
             import_source: default_for_import_source(),
+            inject_imports: default_as_true(),
             pragma: default_for_pragma(),
             pragma_frag: default_for_pragma_frag(),
+            factory: None,
             use_built_ins: None,
             use_spread: None,
         }
@@ -142,6 +439,91 @@ impl Default for ReactOptions {
 }
 
 impl ReactOptions {
+    /// Options for the classic runtime, which compiles JSX to `React.createElement` calls
+    /// and doesn't insert any imports.
+    ///
+    /// ```jsx
+    /// <div />;
+    /// ```
+    /// ```js
+    /// React.createElement('div', null);
+    /// ```
+    pub fn classic() -> Self {
+        Self { runtime: ReactJsxRuntime::Classic, ..Self::default() }
+    }
+
+    /// Options for React 17 projects which haven't moved to the automatic runtime added in
+    /// React 17, and for older versions of React, which only have the classic runtime.
+    ///
+    /// The classic runtime calls `React.createElement` in the same way in every version of React,
+    /// so this is the same as [`ReactOptions::classic`].
+    ///
+    /// ```jsx
+    /// <div />;
+    /// ```
+    /// ```js
+    /// React.createElement('div', null);
+    /// ```
+    pub fn react_17_classic() -> Self {
+        Self::classic()
+    }
+
+    /// Options for the automatic runtime, which compiles JSX to `jsx` and `jsxs` calls
+    /// imported from `react/jsx-runtime`.
+    ///
+    /// ```jsx
+    /// <div />;
+    /// ```
+    /// ```js
+    /// import { jsx as _jsx } from 'react/jsx-runtime';
+    /// _jsx('div', {});
+    /// ```
+    pub fn automatic() -> Self {
+        Self { runtime: ReactJsxRuntime::Automatic, ..Self::default() }
+    }
+
+    /// Options for the automatic runtime in development mode, which compiles JSX to `jsxDEV`
+    /// calls imported from `react/jsx-dev-runtime`, with source locations for debugging.
+    ///
+    /// ```jsx
+    /// <div />;
+    /// ```
+    /// ```js
+    /// var _jsxFileName = '/path/to/test.jsx';
+    /// import { jsxDEV as _jsxDEV } from 'react/jsx-dev-runtime';
+    /// _jsxDEV('div', {}, void 0, false, {
+    ///   fileName: _jsxFileName,
+    ///   lineNumber: 1,
+    ///   columnNumber: 1
+    /// }, this);
+    /// ```
+    pub fn automatic_dev() -> Self {
+        Self { development: true, ..Self::automatic() }
+    }
+
+    /// Options for hyperscript libraries, which compiles JSX to `pragma(tag, props, ...children)`
+    /// calls in the classic runtime, with children always passed flat:
+    /// nested fragments are inlined into their parent's children and other fragments become arrays.
+    ///
+    /// Elements without props pass `null`, set [`ReactOptions::empty_props_value`] to pass `undefined`.
+    ///
+    /// With `ReactOptions::hyperscript("h")`:
+    ///
+    /// ```jsx
+    /// <ul><li>a</li><>b</></ul>;
+    /// ```
+    /// ```js
+    /// h('ul', null, h('li', null, 'a'), 'b');
+    /// ```
+    pub fn hyperscript<S: Into<Cow<'static, str>>>(pragma: S) -> Self {
+        Self {
+            pragma: pragma.into(),
+            fragment_as_array: true,
+            flatten_static_fragments: true,
+            ..Self::classic()
+        }
+    }
+
     pub fn is_jsx_plugin_enabled(&self) -> bool {
         self.jsx_plugin || self.development
     }
@@ -157,6 +539,14 @@ impl ReactOptions {
     /// Scan through all comments and find the following pragmas
     ///
     /// * @jsxRuntime classic / automatic
+    /// * @jsxImportSource
+    /// * @jsxFrag
+    /// * @jsx
+    ///
+    /// Each pragma must be at the start of a line of the comment, after any leading `*`,
+    /// so one JSDoc block can contain several of them, e.g. both `@jsx h` and `@jsxFrag Fragment`.
+    /// The value is the first word after the pragma, and a pragma without a value is ignored,
+    /// e.g. `@jsx h the factory` sets the pragma to `h` and `@jsx` leaves it as it is.
     ///
     /// The comment does not need to be a jsdoc,
     /// otherwise `JSDoc` could be used instead.
@@ -164,44 +554,34 @@ impl ReactOptions {
     /// This behavior is aligned with babel.
     pub(crate) fn update_with_comments(&mut self, ctx: &Ctx) {
         for (_, span) in ctx.trivias.comments() {
-            let mut comment = span.source_text(ctx.source_text).trim_start();
-            // strip leading jsdoc comment `*` and then whitespaces
-            while let Some(cur_comment) = comment.strip_prefix('*') {
-                comment = cur_comment.trim_start();
-            }
-            // strip leading `@`
-            let Some(comment) = comment.strip_prefix('@') else { continue };
-
-            // read jsxRuntime
-            match comment.strip_prefix("jsxRuntime").map(str::trim) {
-                Some("classic") => {
-                    self.runtime = ReactJsxRuntime::Classic;
-                    continue;
+            for line in span.source_text(ctx.source_text).lines() {
+                let mut line = line.trim_start();
+                // strip leading jsdoc comment `*` and then whitespaces
+                while let Some(cur_line) = line.strip_prefix('*') {
+                    line = cur_line.trim_start();
                 }
-                Some("automatic") => {
-                    self.runtime = ReactJsxRuntime::Automatic;
-                    continue;
+                // strip leading `@`
+                if let Some(pragma) = line.strip_prefix('@') {
+                    self.update_with_pragma(pragma);
                 }
-                _ => {}
-            }
-
-            // read jsxImportSource
-            if let Some(import_source) = comment.strip_prefix("jsxImportSource").map(str::trim) {
-                self.import_source = Cow::from(import_source.to_string());
-                continue;
-            }
-
-            // read jsxFrag
-            if let Some(pragma_frag) = comment.strip_prefix("jsxFrag").map(str::trim) {
-                self.pragma_frag = Cow::from(pragma_frag.to_string());
-                continue;
             }
+        }
+    }
 
-            // Put this condition at the end to avoid breaking @jsxXX
-            // read jsx
-            if let Some(pragma) = comment.strip_prefix("jsx").map(str::trim) {
-                self.pragma = Cow::from(pragma.to_string());
-            }
+    /// Update with a pragma without its leading `@`, e.g. `jsxFrag Fragment`.
+    fn update_with_pragma(&mut self, pragma: &str) {
+        let mut words = pragma.split_whitespace();
+        let (Some(name), Some(value)) = (words.next(), words.next()) else { return };
+        match name {
+            "jsxRuntime" => match value {
+                "classic" => self.runtime = ReactJsxRuntime::Classic,
+                "automatic" => self.runtime = ReactJsxRuntime::Automatic,
+                _ => {}
+            },
+            "jsxImportSource" => self.import_source = Cow::from(value.to_string()),
+            "jsxFrag" => self.pragma_frag = Cow::from(value.to_string()),
+            "jsx" => self.pragma = Cow::from(value.to_string()),
+            _ => {}
         }
     }
 }
@@ -1,7 +1,13 @@
 use ropey::Rope;
 
-/// Get line and column from offset and source text
-pub fn get_line_column(offset: u32, source_text: &str) -> (usize, usize) {
+/// Get line and column from offset and source text,
+/// numbered from `line_base` and `column_base`, e.g. `1` for 1-indexed.
+pub fn get_line_column(
+    offset: u32,
+    source_text: &str,
+    line_base: usize,
+    column_base: usize,
+) -> (usize, usize) {
     let offset = offset as usize;
     let rope = Rope::from_str(source_text);
     let line = rope.byte_to_line(offset);
@@ -9,6 +15,15 @@ pub fn get_line_column(offset: u32, source_text: &str) -> (usize, usize) {
     // Original offset is byte, but Rope uses char offset
     let offset = rope.byte_to_char(offset);
     let column = offset - first_char_of_line;
-    // line and column is zero-indexed, but we want 1-indexed
-    (line + 1, column + 1)
+    // line and column are zero-indexed
+    (line + line_base, column + column_base)
+}
+
+#[test]
+fn test_get_line_column() {
+    let source_text = "a;\n  \u{e9}<b />;";
+    let offset = u32::try_from(source_text.find('<').unwrap()).unwrap();
+    assert_eq!(get_line_column(offset, source_text, 0, 0), (1, 3));
+    assert_eq!(get_line_column(offset, source_text, 1, 0), (2, 3));
+    assert_eq!(get_line_column(offset, source_text, 1, 1), (2, 4));
 }
@@ -0,0 +1,83 @@
+//! Checks that the JSX transform decodes text into the traverse context's scratch string,
+//! rather than allocating a `String` for each attribute value and line of text.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    path::Path,
+};
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_transformer::{transform_react_jsx, ReactOptions};
+
+/// Counts the allocations made on each thread, as the tests run in parallel.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[allow(unsafe_code)]
+// SAFETY: Forwards to the system allocator.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Number of heap allocations made while transforming `source_text`, excluding parsing.
+fn count_allocations(source_text: &str) -> usize {
+    let source_type = SourceType::from_path("test.jsx").unwrap();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+
+    let before = ALLOCATIONS.with(Cell::get);
+    let ret = transform_react_jsx(
+        &allocator,
+        Path::new("test.jsx"),
+        source_type,
+        source_text,
+        &ret.trivias,
+        ReactOptions::default(),
+        &mut program,
+    );
+    let allocations = ALLOCATIONS.with(Cell::get) - before;
+    assert!(ret.errors.is_empty());
+    allocations
+}
+
+/// Allocations for `count` repetitions of `item` in `template`, minus those for 10 of them,
+/// which leaves only the allocations made per item.
+fn allocations_per_thousand(template: &str, item: &str) -> usize {
+    let source = |count: usize| template.replace("{}", &item.repeat(count));
+    count_allocations(&source(1010)).saturating_sub(count_allocations(&source(10)))
+}
+
+#[test]
+fn attribute_values() {
+    let allocations = allocations_per_thousand("<div {} />;", " title=\"a &amp; b\"");
+    // Only the arena grows, allocating a few chunks
+    assert!(allocations < 50, "{allocations} allocations for 1000 attribute values");
+}
+
+#[test]
+fn lines_of_text() {
+    let allocations = allocations_per_thousand("<div>{}</div>;", "\n  a &amp; b");
+    assert!(allocations < 50, "{allocations} allocations for 1000 lines of text");
+}
@@ -0,0 +1,201 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, SPAN};
+use oxc_transformer::{ReactJsx, ReactOptions};
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+/// Transforms JSX with a [`ReactJsx`], and inserts the runtime imports only if `flush` is set.
+struct Transform<'a> {
+    jsx: ReactJsx<'a>,
+    flush: bool,
+}
+
+impl<'a> Traverse<'a> for Transform<'a> {
+    fn exit_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        if self.flush {
+            self.jsx.transform_program_on_exit(program);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.jsx.transform_expression(expr, ctx);
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.jsx.transform_expression_on_exit(expr, ctx);
+    }
+}
+
+fn print(program: &Program) -> String {
+    Codegen::<true>::new("", "", CodegenOptions::default()).build(program).source_text
+}
+
+#[test]
+fn take_imports() {
+    let source_text = "import x from 'x';\n<><div /></>;";
+    let source_type = SourceType::from_path("test.jsx").unwrap();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+
+    let jsx = ReactJsx::for_file(
+        &allocator,
+        Path::new("test.jsx"),
+        source_type,
+        source_text,
+        &ret.trivias,
+        ReactOptions::default(),
+    );
+    let mut traverser = Transform { jsx, flush: false };
+    traverse_mut(&mut traverser, &mut program, source_text, source_type, &allocator);
+
+    // Put the runtime imports at the very top, rather than after the existing imports
+    let imports = traverser.jsx.take_imports();
+    assert!(traverser.jsx.take_imports().is_empty());
+    program.body.splice(0..0, imports);
+    assert_eq!(
+        print(&program),
+        "import {jsx as _jsx,Fragment as _Fragment} from 'react/jsx-runtime';\
+        import x from 'x';_jsx(_Fragment,{children:_jsx('div',{})});"
+    );
+    assert!(traverser.jsx.take_errors().is_empty());
+}
+
+#[test]
+fn pending_imports() {
+    let source_text = "<><div /><div /></>;";
+    let source_type = SourceType::from_path("test.jsx").unwrap();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+
+    let jsx = ReactJsx::for_file(
+        &allocator,
+        Path::new("test.jsx"),
+        source_type,
+        source_text,
+        &ret.trivias,
+        ReactOptions::default(),
+    );
+    let mut traverser = Transform { jsx, flush: false };
+    traverse_mut(&mut traverser, &mut program, source_text, source_type, &allocator);
+
+    let expected =
+        "import {jsx as _jsx,Fragment as _Fragment,jsxs as _jsxs} from 'react/jsx-runtime';";
+    let mut imports = Program { body: traverser.jsx.pending_imports(), ..program };
+    assert_eq!(print(&imports), expected);
+    // Looking at the imports leaves them to be inserted
+    imports.body = traverser.jsx.take_imports();
+    assert_eq!(print(&imports), expected);
+    assert!(traverser.jsx.pending_imports().is_empty());
+}
+
+#[test]
+fn begin_file_and_end_file() {
+    // The first file sets its own runtime with a pragma, and the second one is never flushed
+    let files = [
+        ("a.jsx", "/** @jsxRuntime classic */\n<div />;", true),
+        ("b.jsx", "<><span /></>;", false),
+        ("c.jsx", "<div />;", true),
+    ];
+    let source_type = SourceType::from_path("test.jsx").unwrap();
+    let allocator = Allocator::default();
+    let mut traverser: Option<Transform> = None;
+    let mut printed = vec![];
+    for (path, source_text, flush) in files {
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let trivias = allocator.alloc(ret.trivias);
+        let mut program = ret.program;
+
+        let traverser = match &mut traverser {
+            Some(traverser) => {
+                traverser.jsx.begin_file(Path::new(path), source_type, source_text, trivias);
+                traverser.flush = flush;
+                traverser
+            }
+            None => traverser.insert(Transform {
+                jsx: ReactJsx::for_file(
+                    &allocator,
+                    Path::new(path),
+                    source_type,
+                    source_text,
+                    trivias,
+                    ReactOptions::default(),
+                ),
+                flush,
+            }),
+        };
+        traverse_mut(traverser, &mut program, source_text, source_type, &allocator);
+        traverser.jsx.end_file();
+        assert!(traverser.jsx.pending_imports().is_empty());
+        printed.push(print(&program));
+    }
+
+    assert_eq!(
+        printed,
+        [
+            "React.createElement('div',null);",
+            "_jsx(_Fragment,{children:_jsx('span',{})});",
+            "import {jsx as _jsx} from 'react/jsx-runtime';_jsx('div',{});",
+        ]
+    );
+}
+
+/// Collects the runtime calls for the outermost elements and fragments, leaving the JSX in place.
+struct CollectCalls<'a> {
+    jsx: ReactJsx<'a>,
+    calls: Vec<Expression<'a>>,
+}
+
+impl<'a> Traverse<'a> for CollectCalls<'a> {
+    fn enter_jsx_element(&mut self, e: &mut JSXElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !ctx.in_jsx_element() {
+            let call = self.jsx.transform_jsx_element(e, ctx);
+            self.calls.push(call);
+        }
+    }
+
+    fn enter_jsx_fragment(&mut self, e: &mut JSXFragment<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !ctx.in_jsx_element() {
+            let call = self.jsx.transform_jsx_fragment(e, ctx);
+            self.calls.push(call);
+        }
+    }
+}
+
+#[test]
+fn transform_jsx_element_and_fragment() {
+    let source_text = "f(<div><Foo /></div>, <><span /></>);";
+    let source_type = SourceType::from_path("test.jsx").unwrap();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+
+    let jsx = ReactJsx::for_file(
+        &allocator,
+        Path::new("test.jsx"),
+        source_type,
+        source_text,
+        &ret.trivias,
+        ReactOptions::default(),
+    );
+    let mut traverser = CollectCalls { jsx, calls: vec![] };
+    traverse_mut(&mut traverser, &mut program, source_text, source_type, &allocator);
+
+    // The program still has its JSX
+    assert_eq!(print(&program), "f(<div><Foo/></div>,<><span/></>);");
+
+    let ast = AstBuilder::new(&allocator);
+    program.body.clear();
+    program
+        .body
+        .extend(traverser.calls.into_iter().map(|call| ast.expression_statement(SPAN, call)));
+    assert_eq!(
+        print(&program),
+        "_jsx('div',{children:_jsx(Foo,{})});_jsx(_Fragment,{children:_jsx('span',{})});"
+    );
+}
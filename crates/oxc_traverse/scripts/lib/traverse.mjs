@@ -4,7 +4,7 @@ export default function generateTraverseTraitCode(types) {
     const typesArr = Object.values(types);
     typesArr.push({name: 'Statements', rawName: "Vec<'a, Statement<'a>>"});
 
-    let traverseMethods = '';
+    let traverseMethods = '', tupleMethods = '';
     for (const type of typesArr) {
         const snakeName = camelToSnake(type.name);
         traverseMethods += `
@@ -13,6 +13,18 @@ export default function generateTraverseTraitCode(types) {
             #[inline]
             fn exit_${snakeName}(&mut self, node: &mut ${type.rawName}, ctx: &mut TraverseCtx<'a>) {}
         `;
+        tupleMethods += `
+            #[inline]
+            fn enter_${snakeName}(&mut self, node: &mut ${type.rawName}, ctx: &mut TraverseCtx<'a>) {
+                self.0.enter_${snakeName}(node, ctx);
+                self.1.enter_${snakeName}(node, ctx);
+            }
+            #[inline]
+            fn exit_${snakeName}(&mut self, node: &mut ${type.rawName}, ctx: &mut TraverseCtx<'a>) {
+                self.0.exit_${snakeName}(node, ctx);
+                self.1.exit_${snakeName}(node, ctx);
+            }
+        `;
     }
 
     return `
@@ -26,5 +38,16 @@ export default function generateTraverseTraitCode(types) {
         pub trait Traverse<'a> {
             ${traverseMethods}
         }
+
+        /// Run 2 visitors in a single traversal.
+        ///
+        /// At each node, both \`enter_*\` methods are called in order, and then,
+        /// after the node's children are visited, both \`exit_*\` methods in the same order.
+        /// The second visitor sees any changes the first one made to the node.
+        ///
+        /// Nest tuples to compose more than 2 visitors, e.g. \`(a, (b, c))\`.
+        impl<'a, A: Traverse<'a>, B: Traverse<'a>> Traverse<'a> for (A, B) {
+            ${tupleMethods}
+        }
     `;
 }
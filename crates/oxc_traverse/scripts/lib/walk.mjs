@@ -91,6 +91,15 @@ function generateWalkForStruct(type, types) {
         `;
     }
 
+    // `Program` and `FunctionBody` receive statements hoisted with `TraverseCtx::hoist_to_scope`
+    let exitHoistCode = '';
+    const hoistFieldName = {Program: 'body', FunctionBody: 'statements'}[type.name];
+    if (hoistFieldName) {
+        const hoistField = visitedFields.find(field => field.name === hoistFieldName);
+        assert(hoistField, `Cannot find field '${hoistFieldName}' to hoist statements into in '${type.name}'`);
+        exitHoistCode = `ctx.exit_hoist_scope(&mut *(${makeFieldCode(hoistField)}));`;
+    }
+
     const fieldsCodes = visitedFields.map((field, index) => {
         const fieldWalkName = `walk_${camelToSnake(field.innerTypeName)}`;
 
@@ -191,6 +200,15 @@ function generateWalkForStruct(type, types) {
             );
         `);
         fieldsCodes.push('ctx.pop_stack();');
+        // In debug builds, check the stack is restored, to pinpoint which node type is unbalanced
+        fieldsCodes.unshift(`
+            #[cfg(debug_assertions)]
+            let stack_depth = ctx.ancestors_depth();
+        `);
+        fieldsCodes.push(`
+            #[cfg(debug_assertions)]
+            ctx.assert_stack_balanced(stack_depth, "${type.name}");
+        `);
     }
 
     const typeSnakeName = camelToSnake(type.name);
@@ -201,9 +219,12 @@ function generateWalkForStruct(type, types) {
             ctx: &mut TraverseCtx<'a>
         ) {
             ${enterScopeCode}
+            #[cfg(feature = "visit_counts")]
+            ctx.count_visit("${type.name}");
             traverser.enter_${typeSnakeName}(&mut *node, ctx);
             ${fieldsCodes.join('\n')}
             traverser.exit_${typeSnakeName}(&mut *node, ctx);
+            ${exitHoistCode}
             ${exitScopeCode}
         }
     `.replace(/\n\s*\n+/g, '\n');
@@ -262,7 +283,18 @@ function generateWalkForEnum(type, types) {
             node: *mut ${type.rawName},
             ctx: &mut TraverseCtx<'a>
         ) {
+            #[cfg(feature = "revisit")]
+            ctx.clear_revisit();
             traverser.enter_${typeSnakeName}(&mut *node, ctx);
+            // Visit the node again if \`enter_*\` replaced it and asked for it to be revisited
+            #[cfg(feature = "revisit")]
+            {
+                let mut revisits = 0;
+                while ctx.take_revisit(revisits) {
+                    revisits += 1;
+                    traverser.enter_${typeSnakeName}(&mut *node, ctx);
+                }
+            }
             match &mut *node {
                 ${variantCodes.join('\n')}
             }
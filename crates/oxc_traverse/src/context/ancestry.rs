@@ -1,3 +1,5 @@
+use oxc_ast::ast::FormalParameters;
+
 use super::FinderRet;
 use crate::ancestor::{Ancestor, AncestorType};
 
@@ -100,6 +102,91 @@ impl<'a> TraverseAncestry<'a> {
     pub fn ancestors_depth(&self) -> usize {
         self.stack.len()
     }
+
+    /// Get if current node is inside a JSX element or JSX fragment.
+    ///
+    /// This includes the element's name, attributes and children,
+    /// and expressions nested in them at any depth, e.g. `x` in `<div>{() => x}</div>`.
+    pub fn in_jsx_element(&self) -> bool {
+        self.stack
+            .iter()
+            .rev()
+            .any(|ancestor| ancestor.is_jsx_element() || ancestor.is_jsx_fragment())
+    }
+
+    /// Get if current node is inside a JSX attribute or JSX spread attribute.
+    ///
+    /// e.g. `x` in `<div id={x} />` or `<div {...x} />`.
+    pub fn in_jsx_attribute(&self) -> bool {
+        self.stack
+            .iter()
+            .rev()
+            .any(|ancestor| ancestor.is_jsx_attribute() || ancestor.is_jsx_spread_attribute())
+    }
+
+    /// Get if current node is the value returned from a function.
+    ///
+    /// e.g. `x` in `return x`, `return (x)` or `() => x`.
+    pub fn is_return_value(&self) -> bool {
+        let mut ancestors = self.ancestors_outside_parentheses();
+        match ancestors.next() {
+            Some(Ancestor::ReturnStatementArgument(_)) => true,
+            Some(Ancestor::ExpressionStatementExpression(_)) => {
+                Self::is_arrow_function_expression_body(ancestors)
+            }
+            _ => false,
+        }
+    }
+
+    /// Get if current node is the expression of an expression statement, so its value is unused.
+    ///
+    /// e.g. `x` in `x;` or `(x);`, but not in `() => x`.
+    pub fn is_statement_expression(&self) -> bool {
+        let mut ancestors = self.ancestors_outside_parentheses();
+        match ancestors.next() {
+            Some(Ancestor::ExpressionStatementExpression(_)) => {
+                !Self::is_arrow_function_expression_body(ancestors)
+            }
+            _ => false,
+        }
+    }
+
+    /// Get parameters of the nearest function or arrow function whose body contains current node.
+    ///
+    /// e.g. `(a, b)` for `x` in `function f(a, b) { if (c) { x } }`, `() => x` or `{ m(a, b) { x } }`.
+    ///
+    /// Returns `None` if current node is not in a function body, or is in the parameters
+    /// of the nearest function, e.g. `x` in `function f(a = x) {}`, as they are being visited.
+    pub fn enclosing_function_params<'c>(&'c self) -> Option<&'c FormalParameters<'a>> {
+        self.find_ancestor(|ancestor| match ancestor {
+            Ancestor::FunctionBody(func) => FinderRet::Found(&**func.params()),
+            Ancestor::ArrowFunctionExpressionBody(arrow) => FinderRet::Found(&**arrow.params()),
+            Ancestor::FunctionParams(_) | Ancestor::ArrowFunctionExpressionParams(_) => {
+                FinderRet::Stop
+            }
+            _ => FinderRet::Continue,
+        })
+    }
+
+    /// Ancestors from the parent up, skipping parenthesized expressions around current node.
+    fn ancestors_outside_parentheses(&self) -> impl Iterator<Item = &Ancestor<'a>> {
+        self.stack.iter().rev().skip_while(|ancestor| ancestor.is_parenthesized_expression())
+    }
+
+    /// Whether the ancestors above an expression statement are the expression body
+    /// of an arrow function, e.g. `() => x`.
+    fn is_arrow_function_expression_body<'c>(
+        mut ancestors: impl Iterator<Item = &'c Ancestor<'a>>,
+    ) -> bool
+    where
+        'a: 'c,
+    {
+        matches!(ancestors.next(), Some(Ancestor::FunctionBodyStatements(_)))
+            && matches!(
+                ancestors.next(),
+                Some(Ancestor::ArrowFunctionExpressionBody(arrow)) if *arrow.expression()
+            )
+    }
 }
 
 // Methods used internally within crate.
@@ -114,6 +201,29 @@ impl<'a> TraverseAncestry<'a> {
         Self { stack }
     }
 
+    /// Get depth of the stack when the nearest `Program` or `FunctionBody` which can receive
+    /// statements from [`TraverseCtx::hoist_to_scope`] was entered.
+    ///
+    /// The expression body of an arrow function is skipped, and `Program` is the fallback
+    /// when called from visitors for `Program` itself.
+    ///
+    /// [`TraverseCtx::hoist_to_scope`]: super::TraverseCtx::hoist_to_scope
+    pub(super) fn hoist_scope_depth(&self) -> usize {
+        // `stack[0]` is the initial entry, and `stack[1]` is for `Program` when it's being walked
+        (2..self.stack.len())
+            .rev()
+            .find(|&depth| match &self.stack[depth] {
+                Ancestor::FunctionBodyDirectives(_) | Ancestor::FunctionBodyStatements(_) => {
+                    !matches!(
+                        &self.stack[depth - 1],
+                        Ancestor::ArrowFunctionExpressionBody(arrow) if *arrow.expression()
+                    )
+                }
+                _ => false,
+            })
+            .unwrap_or(1)
+    }
+
     /// Push item onto ancestry stack.
     ///
     /// # SAFETY
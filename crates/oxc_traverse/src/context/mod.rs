@@ -1,7 +1,10 @@
-use oxc_allocator::{Allocator, Box};
-use oxc_ast::AstBuilder;
+use oxc_allocator::{Allocator, Box, Vec};
+use oxc_ast::{
+    ast::{FormalParameters, Statement},
+    AstBuilder,
+};
 use oxc_semantic::{ScopeTree, SymbolTable};
-use oxc_span::CompactStr;
+use oxc_span::{CompactStr, SourceType, Span};
 use oxc_syntax::{
     scope::{ScopeFlags, ScopeId},
     symbol::SymbolFlags,
@@ -13,6 +16,10 @@ mod ancestry;
 pub use ancestry::TraverseAncestry;
 mod scoping;
 pub use scoping::TraverseScoping;
+#[cfg(feature = "visit_counts")]
+mod visit_counts;
+#[cfg(feature = "visit_counts")]
+pub use visit_counts::VisitCounts;
 
 /// Traverse context.
 ///
@@ -20,10 +27,15 @@ pub use scoping::TraverseScoping;
 ///
 /// Provides ability to:
 /// * Query parent/ancestor of current node via [`parent`], [`ancestor`], [`find_ancestor`].
+/// * Query if current node is inside JSX via [`in_jsx_element`], [`in_jsx_attribute`].
+/// * Query position of current node via [`is_return_value`], [`is_statement_expression`].
+/// * Get parameters of the enclosing function via [`enclosing_function_params`].
 /// * Get scopes tree and symbols table via [`scopes`], [`symbols`], [`scopes_mut`], [`symbols_mut`],
 ///   [`find_scope`], [`find_scope_by_flags`].
 /// * Create AST nodes via AST builder [`ast`].
 /// * Allocate into arena via [`alloc`].
+/// * Hoist statements to the top of the enclosing function or program via [`hoist_to_scope`].
+/// * Build temporary strings without allocating for each node via [`take_scratch_string`].
 ///
 /// # Namespaced APIs
 ///
@@ -92,6 +104,11 @@ pub use scoping::TraverseScoping;
 /// [`parent`]: `TraverseCtx::parent`
 /// [`ancestor`]: `TraverseCtx::ancestor`
 /// [`find_ancestor`]: `TraverseCtx::find_ancestor`
+/// [`in_jsx_element`]: `TraverseCtx::in_jsx_element`
+/// [`in_jsx_attribute`]: `TraverseCtx::in_jsx_attribute`
+/// [`is_return_value`]: `TraverseCtx::is_return_value`
+/// [`is_statement_expression`]: `TraverseCtx::is_statement_expression`
+/// [`enclosing_function_params`]: `TraverseCtx::enclosing_function_params`
 /// [`scopes`]: `TraverseCtx::scopes`
 /// [`symbols`]: `TraverseCtx::symbols`
 /// [`scopes_mut`]: `TraverseCtx::scopes_mut`
@@ -100,12 +117,33 @@ pub use scoping::TraverseScoping;
 /// [`find_scope_by_flags`]: `TraverseCtx::find_scope_by_flags`
 /// [`ast`]: `TraverseCtx::ast`
 /// [`alloc`]: `TraverseCtx::alloc`
+/// [`hoist_to_scope`]: `TraverseCtx::hoist_to_scope`
+/// [`take_scratch_string`]: `TraverseCtx::take_scratch_string`
 pub struct TraverseCtx<'a> {
     pub ancestry: TraverseAncestry<'a>,
     pub scoping: TraverseScoping,
     pub ast: AstBuilder<'a>,
+    /// Source type of the program being traversed
+    source_type: SourceType,
+    /// Span of the program being traversed
+    program_span: Span,
+    /// Statements queued by [`TraverseCtx::hoist_to_scope`], one entry per function/program
+    /// currently being visited which has any, keyed and sorted by ancestry stack depth
+    hoisted_statements: std::vec::Vec<(usize, std::vec::Vec<Statement<'a>>)>,
+    /// Buffer lent out by [`TraverseCtx::take_scratch_string`]
+    scratch_string: String,
+    /// Set by [`TraverseCtx::revisit`]
+    #[cfg(feature = "revisit")]
+    revisit: bool,
+    #[cfg(feature = "visit_counts")]
+    pub(crate) visit_counts: VisitCounts,
 }
 
+/// Maximum number of times [`TraverseCtx::revisit`] can re-enter the same node,
+/// to prevent infinite loops when a visitor keeps replacing a node.
+#[cfg(feature = "revisit")]
+pub(crate) const MAX_REVISITS: u32 = 32;
+
 /// Return value of closure when using [`TraverseCtx::find_ancestor`] or [`TraverseCtx::find_scope`].
 pub enum FinderRet<T> {
     Found(T),
@@ -116,11 +154,29 @@ pub enum FinderRet<T> {
 // Public methods
 impl<'a> TraverseCtx<'a> {
     /// Create new traversal context.
-    pub(crate) fn new(scopes: ScopeTree, symbols: SymbolTable, allocator: &'a Allocator) -> Self {
+    pub(crate) fn new(
+        scopes: ScopeTree,
+        symbols: SymbolTable,
+        source_type: SourceType,
+        program_span: Span,
+        allocator: &'a Allocator,
+    ) -> Self {
         let ancestry = TraverseAncestry::new();
         let scoping = TraverseScoping::new(scopes, symbols);
         let ast = AstBuilder::new(allocator);
-        Self { ancestry, scoping, ast }
+        Self {
+            ancestry,
+            scoping,
+            ast,
+            source_type,
+            program_span,
+            hoisted_statements: vec![],
+            scratch_string: String::new(),
+            #[cfg(feature = "revisit")]
+            revisit: false,
+            #[cfg(feature = "visit_counts")]
+            visit_counts: VisitCounts::default(),
+        }
     }
 
     /// Allocate a node in the arena.
@@ -133,6 +189,140 @@ impl<'a> TraverseCtx<'a> {
         self.ast.alloc(node)
     }
 
+    /// Get source type of the program being traversed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxc_ast::ast::JSXElement;
+    /// use oxc_traverse::{Traverse, TraverseCtx};
+    ///
+    /// struct MyTraverse;
+    /// impl<'a> Traverse<'a> for MyTraverse {
+    ///     fn enter_jsx_element(&mut self, _elem: &mut JSXElement<'a>, ctx: &mut TraverseCtx<'a>) {
+    ///         if ctx.source_type().is_typescript() {
+    ///             println!("JSX in a TypeScript file");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn source_type(&self) -> SourceType {
+        self.source_type
+    }
+
+    /// Get span of the program being traversed.
+    #[inline]
+    pub fn program_span(&self) -> Span {
+        self.program_span
+    }
+
+    /// Queue a statement to be inserted at the top of the nearest enclosing function or program.
+    ///
+    /// Statements are inserted once the function body or program has been exited,
+    /// after the `exit_*` visitor for it has run, in the order they were queued.
+    ///
+    /// An arrow function with an expression body has no statements to insert into,
+    /// so statements hoisted from inside it go to the next function or program up.
+    /// So do statements hoisted from `enter_function_body` or `exit_function_body`,
+    /// as the function body isn't one of the ancestors of the node being visited.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxc_ast::ast::*;
+    /// use oxc_traverse::{Traverse, TraverseCtx};
+    ///
+    /// struct MyTraverse;
+    /// impl<'a> Traverse<'a> for MyTraverse {
+    ///     fn enter_debugger_statement(&mut self, stmt: &mut DebuggerStatement, ctx: &mut TraverseCtx<'a>) {
+    ///         // Add another `debugger;` at top of the enclosing function
+    ///         let stmt = ctx.ast.debugger_statement(stmt.span);
+    ///         ctx.hoist_to_scope(stmt);
+    ///     }
+    /// }
+    /// ```
+    pub fn hoist_to_scope(&mut self, stmt: Statement<'a>) {
+        let depth = self.ancestry.hoist_scope_depth();
+        // Usually the last entry, except when hoisting into an outer function
+        // from `exit_function_body` of one which has hoisted statements too
+        let index =
+            self.hoisted_statements.partition_point(|(entry_depth, _)| *entry_depth < depth);
+        match self.hoisted_statements.get_mut(index) {
+            Some((entry_depth, hoisted)) if *entry_depth == depth => hoisted.push(stmt),
+            _ => self.hoisted_statements.insert(index, (depth, vec![stmt])),
+        }
+    }
+
+    /// Take the scratch string, for building a temporary string without allocating for each node.
+    ///
+    /// The string is empty, but keeps the capacity it grew to when it was last used.
+    /// Give it back with [`TraverseCtx::recycle_scratch_string`] when done with it, otherwise
+    /// the next call returns a new string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxc_ast::ast::*;
+    /// use oxc_traverse::{Traverse, TraverseCtx};
+    ///
+    /// struct MyTraverse;
+    /// impl<'a> Traverse<'a> for MyTraverse {
+    ///     fn enter_string_literal(&mut self, lit: &mut StringLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+    ///         let mut upper = ctx.take_scratch_string();
+    ///         upper.extend(lit.value.chars().flat_map(char::to_uppercase));
+    ///         // Only the arena allocates, for the new value
+    ///         lit.value = ctx.ast.new_atom(&upper);
+    ///         ctx.recycle_scratch_string(upper);
+    ///     }
+    /// }
+    /// ```
+    pub fn take_scratch_string(&mut self) -> String {
+        let mut s = std::mem::take(&mut self.scratch_string);
+        s.clear();
+        s
+    }
+
+    /// Give back the string returned by [`TraverseCtx::take_scratch_string`], so the next call
+    /// can reuse its allocation.
+    pub fn recycle_scratch_string(&mut self, s: String) {
+        self.scratch_string = s;
+    }
+
+    /// Visit the current node again after replacing it, so that `enter_*` is called on the replacement.
+    ///
+    /// Only nodes of enum types (e.g. `Expression`, `Statement`) can be revisited,
+    /// so call this from `enter_*` for an enum type, after assigning a new node to it.
+    /// The children of the replacement are visited either way.
+    ///
+    /// A node is revisited at most 32 times, in case it is replaced endlessly.
+    /// Asking for more revisits than that panics in debug builds.
+    ///
+    /// Only available with the `revisit` feature, as checking for revisits slows down
+    /// visiting every enum node.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxc_ast::ast::*;
+    /// use oxc_traverse::{Traverse, TraverseCtx};
+    ///
+    /// struct MyTraverse;
+    /// impl<'a> Traverse<'a> for MyTraverse {
+    ///     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+    ///         // Unwrap `(x)` until it's not parenthesized anymore
+    ///         if let Expression::ParenthesizedExpression(paren) = expr {
+    ///             *expr = ctx.ast.move_expression(&mut paren.expression);
+    ///             ctx.revisit();
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "revisit")]
+    pub fn revisit(&mut self) {
+        self.revisit = true;
+    }
+
     /// Get parent of current node.
     ///
     /// Shortcut for `ctx.ancestry.parent`.
@@ -200,6 +390,46 @@ impl<'a> TraverseCtx<'a> {
         self.ancestry.ancestors_depth()
     }
 
+    /// Get if current node is inside a JSX element or JSX fragment.
+    ///
+    /// Shortcut for `self.ancestry.in_jsx_element`.
+    #[inline]
+    pub fn in_jsx_element(&self) -> bool {
+        self.ancestry.in_jsx_element()
+    }
+
+    /// Get if current node is inside a JSX attribute or JSX spread attribute.
+    ///
+    /// Shortcut for `self.ancestry.in_jsx_attribute`.
+    #[inline]
+    pub fn in_jsx_attribute(&self) -> bool {
+        self.ancestry.in_jsx_attribute()
+    }
+
+    /// Get if current node is the value returned from a function.
+    ///
+    /// Shortcut for `self.ancestry.is_return_value`.
+    #[inline]
+    pub fn is_return_value(&self) -> bool {
+        self.ancestry.is_return_value()
+    }
+
+    /// Get if current node is the expression of an expression statement.
+    ///
+    /// Shortcut for `self.ancestry.is_statement_expression`.
+    #[inline]
+    pub fn is_statement_expression(&self) -> bool {
+        self.ancestry.is_statement_expression()
+    }
+
+    /// Get parameters of the nearest function whose body contains current node.
+    ///
+    /// Shortcut for `self.ancestry.enclosing_function_params`.
+    #[inline]
+    pub fn enclosing_function_params<'c>(&'c self) -> Option<&'c FormalParameters<'a>> {
+        self.ancestry.enclosing_function_params()
+    }
+
     /// Get current scope ID.
     ///
     /// Shortcut for `ctx.scoping.current_scope_id`.
@@ -332,4 +562,110 @@ impl<'a> TraverseCtx<'a> {
     pub(crate) fn set_current_scope_id(&mut self, scope_id: ScopeId) {
         self.scoping.set_current_scope_id(scope_id);
     }
+
+    /// Record a visit to a node of type `type_name`, to make `walk_*` methods less verbose.
+    #[cfg(feature = "visit_counts")]
+    #[inline]
+    pub(crate) fn count_visit(&mut self, type_name: &'static str) {
+        self.visit_counts.increment(type_name);
+    }
+
+    /// Assert that the ancestry stack is back at `depth`, which it had when the `walk_*` method
+    /// for a node of type `type_name` was entered, i.e. every `push_stack` had a matching `pop_stack`.
+    ///
+    /// Catches an unbalanced `walk_*` method at the node which caused it, rather than only once
+    /// the whole AST has been walked.
+    #[cfg(debug_assertions)]
+    #[inline]
+    pub(crate) fn assert_stack_balanced(&self, depth: usize, type_name: &'static str) {
+        assert!(
+            self.ancestors_depth() == depth,
+            "Ancestry stack is unbalanced after walking `{type_name}`: depth {} instead of {depth}",
+            self.ancestors_depth()
+        );
+    }
+
+    /// Clear a [`TraverseCtx::revisit`] request left over from another node,
+    /// before entering a node which can be revisited.
+    #[cfg(feature = "revisit")]
+    #[inline]
+    pub(crate) fn clear_revisit(&mut self) {
+        self.revisit = false;
+    }
+
+    /// Take [`TraverseCtx::revisit`] request for the node which was just entered,
+    /// after it has already been revisited `revisits` times.
+    ///
+    /// Once the node has been revisited [`MAX_REVISITS`] times, requests are ignored.
+    #[cfg(feature = "revisit")]
+    #[inline]
+    pub(crate) fn take_revisit(&mut self, revisits: u32) -> bool {
+        if !std::mem::take(&mut self.revisit) {
+            return false;
+        }
+        debug_assert!(
+            revisits < MAX_REVISITS,
+            "Node was revisited {MAX_REVISITS} times, is `enter_*` replacing it endlessly?"
+        );
+        revisits < MAX_REVISITS
+    }
+
+    /// Insert statements queued by [`TraverseCtx::hoist_to_scope`] for the `Program` or
+    /// `FunctionBody` which was just exited at start of its `stmts`, if there are any.
+    pub(crate) fn exit_hoist_scope(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        // Entries of nested functions were taken on exiting them, so this one's entry is last
+        if self.hoisted_statements.last().is_some_and(|(depth, _)| *depth == self.ancestors_depth())
+        {
+            let (_, hoisted) = self.hoisted_statements.pop().unwrap();
+            stmts.splice(0..0, hoisted);
+        }
+    }
+}
+
+#[cfg(all(test, debug_assertions))]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_semantic::{ScopeTree, SymbolTable};
+    use oxc_span::{SourceType, SPAN};
+
+    use super::TraverseCtx;
+    use crate::Ancestor;
+
+    #[test]
+    fn balanced_stack() {
+        let allocator = Allocator::default();
+        let mut ctx = TraverseCtx::new(
+            ScopeTree::default(),
+            SymbolTable::default(),
+            SourceType::default(),
+            SPAN,
+            &allocator,
+        );
+        let depth = ctx.ancestors_depth();
+        ctx.push_stack(Ancestor::None);
+        // SAFETY: Pops the entry pushed above
+        #[allow(unsafe_code)]
+        unsafe {
+            ctx.pop_stack();
+        }
+        ctx.assert_stack_balanced(depth, "Program");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Ancestry stack is unbalanced after walking `Program`: depth 2 instead of 1"
+    )]
+    fn unbalanced_stack() {
+        let allocator = Allocator::default();
+        let mut ctx = TraverseCtx::new(
+            ScopeTree::default(),
+            SymbolTable::default(),
+            SourceType::default(),
+            SPAN,
+            &allocator,
+        );
+        let depth = ctx.ancestors_depth();
+        ctx.push_stack(Ancestor::None);
+        ctx.assert_stack_balanced(depth, "Program");
+    }
 }
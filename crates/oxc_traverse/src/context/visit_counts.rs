@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+/// Number of times each type of AST node was visited.
+///
+/// Returned by [`crate::traverse_mut_with_visit_counts`].
+/// Only available with the `visit_counts` feature.
+///
+/// Counts are keyed by the name of the node's struct type e.g. `"JSXElement"`.
+/// Enums like `Expression` are not counted, as visiting them visits the struct they contain.
+#[derive(Debug, Default, Clone)]
+pub struct VisitCounts {
+    counts: HashMap<&'static str, usize>,
+}
+
+// Public methods
+impl VisitCounts {
+    /// Get number of visits to nodes of type `type_name`.
+    pub fn get(&self, type_name: &str) -> usize {
+        self.counts.get(type_name).copied().unwrap_or(0)
+    }
+
+    /// Get total number of nodes visited.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Iterate over `(type_name, count)` pairs, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, usize)> + '_ {
+        self.counts.iter().map(|(type_name, count)| (*type_name, *count))
+    }
+}
+
+// Methods used internally within crate
+impl VisitCounts {
+    #[inline]
+    pub(crate) fn increment(&mut self, type_name: &'static str) {
+        *self.counts.entry(type_name).or_default() += 1;
+    }
+}
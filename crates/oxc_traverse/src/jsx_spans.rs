@@ -0,0 +1,40 @@
+use oxc_ast::ast::{JSXElement, JSXFragment};
+use oxc_span::Span;
+
+use crate::{Traverse, TraverseCtx};
+
+/// [`Traverse`] impl which collects the spans of all JSX elements and fragments, without
+/// changing the AST, e.g. for folding ranges in editors.
+///
+/// Spans are in the order the nodes are entered, so parents come before their children.
+/// Can be run in the same walk as another traverser by traversing with a tuple of both.
+///
+/// ```
+/// use oxc_allocator::Allocator;
+/// use oxc_parser::Parser;
+/// use oxc_span::{SourceType, Span};
+/// use oxc_traverse::{traverse_mut, JsxSpans};
+///
+/// let source_text = "<div><>a</></div>;";
+/// let source_type = SourceType::default().with_jsx(true);
+/// let allocator = Allocator::default();
+/// let mut program = Parser::new(&allocator, source_text, source_type).parse().program;
+///
+/// let mut jsx_spans = JsxSpans::default();
+/// traverse_mut(&mut jsx_spans, &mut program, source_text, source_type, &allocator);
+/// assert_eq!(jsx_spans.spans, [Span::new(0, 17), Span::new(5, 11)]);
+/// ```
+#[derive(Debug, Default)]
+pub struct JsxSpans {
+    pub spans: Vec<Span>,
+}
+
+impl<'a> Traverse<'a> for JsxSpans {
+    fn enter_jsx_element(&mut self, node: &mut JSXElement<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.spans.push(node.span);
+    }
+
+    fn enter_jsx_fragment(&mut self, node: &mut JSXFragment<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.spans.push(node.span);
+    }
+}
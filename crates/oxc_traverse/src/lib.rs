@@ -68,7 +68,11 @@ use oxc_span::SourceType;
 pub mod ancestor;
 pub use ancestor::Ancestor;
 mod context;
+#[cfg(feature = "visit_counts")]
+pub use context::VisitCounts;
 pub use context::{FinderRet, TraverseAncestry, TraverseCtx, TraverseScoping};
+mod jsx_spans;
+pub use jsx_spans::JsxSpans;
 #[allow(clippy::module_inception)]
 mod traverse;
 pub use traverse::Traverse;
@@ -137,7 +141,6 @@ mod walk;
 ///     }
 /// }
 /// ```
-#[allow(unsafe_code)]
 pub fn traverse_mut<'a, Tr: Traverse<'a>>(
     traverser: &mut Tr,
     program: &mut Program<'a>,
@@ -145,14 +148,41 @@ pub fn traverse_mut<'a, Tr: Traverse<'a>>(
     source_type: SourceType,
     allocator: &'a Allocator,
 ) {
+    traverse_program(traverser, program, source_text, source_type, allocator);
+}
+
+/// Traverse AST with a [`Traverse`] impl, same as [`traverse_mut`],
+/// and count how many times each type of AST node was visited.
+///
+/// Only available with the `visit_counts` feature.
+#[cfg(feature = "visit_counts")]
+pub fn traverse_mut_with_visit_counts<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    program: &mut Program<'a>,
+    source_text: &'a str,
+    source_type: SourceType,
+    allocator: &'a Allocator,
+) -> VisitCounts {
+    traverse_program(traverser, program, source_text, source_type, allocator).visit_counts
+}
+
+#[allow(unsafe_code)]
+fn traverse_program<'a, Tr: Traverse<'a>>(
+    traverser: &mut Tr,
+    program: &mut Program<'a>,
+    source_text: &'a str,
+    source_type: SourceType,
+    allocator: &'a Allocator,
+) -> TraverseCtx<'a> {
     let semantic = SemanticBuilder::new(source_text, source_type)
         .with_check_syntax_error(true)
         .build(program)
         .semantic;
     let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
 
-    let mut ctx = TraverseCtx::new(scopes, symbols, allocator);
+    let mut ctx = TraverseCtx::new(scopes, symbols, source_type, program.span, allocator);
     // SAFETY: Walk functions are constructed to avoid unsoundness
     unsafe { walk::walk_program(traverser, program as *mut Program, &mut ctx) };
     debug_assert!(ctx.ancestors_depth() == 1);
+    ctx
 }
@@ -36,7 +36,11 @@ pub(crate) unsafe fn walk_program<'a, Tr: Traverse<'a>>(
         previous_scope_id = Some(ctx.current_scope_id());
         ctx.set_current_scope_id(scope_id);
     }
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("Program");
     traverser.enter_program(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ProgramDirectives(ancestor::ProgramWithoutDirectives(node)));
     for item in (*((node as *mut u8).add(ancestor::OFFSET_PROGRAM_DIRECTIVES)
         as *mut Vec<Directive>))
@@ -57,7 +61,12 @@ pub(crate) unsafe fn walk_program<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "Program");
     traverser.exit_program(&mut *node, ctx);
+    ctx.exit_hoist_scope(
+        &mut *((node as *mut u8).add(ancestor::OFFSET_PROGRAM_BODY) as *mut Vec<Statement>),
+    );
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
     }
@@ -68,7 +77,18 @@ pub(crate) unsafe fn walk_expression<'a, Tr: Traverse<'a>>(
     node: *mut Expression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_expression(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_expression(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         Expression::BooleanLiteral(node) => {
             walk_boolean_literal(traverser, (&mut **node) as *mut _, ctx)
@@ -191,6 +211,8 @@ pub(crate) unsafe fn walk_identifier_name<'a, Tr: Traverse<'a>>(
     node: *mut IdentifierName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("IdentifierName");
     traverser.enter_identifier_name(&mut *node, ctx);
     traverser.exit_identifier_name(&mut *node, ctx);
 }
@@ -200,6 +222,8 @@ pub(crate) unsafe fn walk_identifier_reference<'a, Tr: Traverse<'a>>(
     node: *mut IdentifierReference<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("IdentifierReference");
     traverser.enter_identifier_reference(&mut *node, ctx);
     traverser.exit_identifier_reference(&mut *node, ctx);
 }
@@ -209,6 +233,8 @@ pub(crate) unsafe fn walk_binding_identifier<'a, Tr: Traverse<'a>>(
     node: *mut BindingIdentifier<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("BindingIdentifier");
     traverser.enter_binding_identifier(&mut *node, ctx);
     traverser.exit_binding_identifier(&mut *node, ctx);
 }
@@ -218,6 +244,8 @@ pub(crate) unsafe fn walk_label_identifier<'a, Tr: Traverse<'a>>(
     node: *mut LabelIdentifier<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("LabelIdentifier");
     traverser.enter_label_identifier(&mut *node, ctx);
     traverser.exit_label_identifier(&mut *node, ctx);
 }
@@ -227,6 +255,8 @@ pub(crate) unsafe fn walk_this_expression<'a, Tr: Traverse<'a>>(
     node: *mut ThisExpression,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ThisExpression");
     traverser.enter_this_expression(&mut *node, ctx);
     traverser.exit_this_expression(&mut *node, ctx);
 }
@@ -236,7 +266,11 @@ pub(crate) unsafe fn walk_array_expression<'a, Tr: Traverse<'a>>(
     node: *mut ArrayExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ArrayExpression");
     traverser.enter_array_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ArrayExpressionElements(ancestor::ArrayExpressionWithoutElements(
         node,
     )));
@@ -247,6 +281,8 @@ pub(crate) unsafe fn walk_array_expression<'a, Tr: Traverse<'a>>(
         walk_array_expression_element(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ArrayExpression");
     traverser.exit_array_expression(&mut *node, ctx);
 }
 
@@ -255,7 +291,18 @@ pub(crate) unsafe fn walk_array_expression_element<'a, Tr: Traverse<'a>>(
     node: *mut ArrayExpressionElement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_array_expression_element(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_array_expression_element(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         ArrayExpressionElement::SpreadElement(node) => {
             walk_spread_element(traverser, (&mut **node) as *mut _, ctx)
@@ -314,6 +361,8 @@ pub(crate) unsafe fn walk_elision<'a, Tr: Traverse<'a>>(
     node: *mut Elision,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("Elision");
     traverser.enter_elision(&mut *node, ctx);
     traverser.exit_elision(&mut *node, ctx);
 }
@@ -323,7 +372,11 @@ pub(crate) unsafe fn walk_object_expression<'a, Tr: Traverse<'a>>(
     node: *mut ObjectExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ObjectExpression");
     traverser.enter_object_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ObjectExpressionProperties(
         ancestor::ObjectExpressionWithoutProperties(node),
     ));
@@ -334,6 +387,8 @@ pub(crate) unsafe fn walk_object_expression<'a, Tr: Traverse<'a>>(
         walk_object_property_kind(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ObjectExpression");
     traverser.exit_object_expression(&mut *node, ctx);
 }
 
@@ -342,7 +397,18 @@ pub(crate) unsafe fn walk_object_property_kind<'a, Tr: Traverse<'a>>(
     node: *mut ObjectPropertyKind<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_object_property_kind(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_object_property_kind(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         ObjectPropertyKind::ObjectProperty(node) => {
             walk_object_property(traverser, (&mut **node) as *mut _, ctx)
@@ -359,7 +425,11 @@ pub(crate) unsafe fn walk_object_property<'a, Tr: Traverse<'a>>(
     node: *mut ObjectProperty<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ObjectProperty");
     traverser.enter_object_property(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ObjectPropertyKey(ancestor::ObjectPropertyWithoutKey(node)));
     walk_property_key(
         traverser,
@@ -379,6 +449,8 @@ pub(crate) unsafe fn walk_object_property<'a, Tr: Traverse<'a>>(
         walk_expression(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ObjectProperty");
     traverser.exit_object_property(&mut *node, ctx);
 }
 
@@ -387,7 +459,18 @@ pub(crate) unsafe fn walk_property_key<'a, Tr: Traverse<'a>>(
     node: *mut PropertyKey<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_property_key(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_property_key(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         PropertyKey::StaticIdentifier(node) => {
             walk_identifier_name(traverser, (&mut **node) as *mut _, ctx)
@@ -446,7 +529,11 @@ pub(crate) unsafe fn walk_template_literal<'a, Tr: Traverse<'a>>(
     node: *mut TemplateLiteral<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TemplateLiteral");
     traverser.enter_template_literal(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TemplateLiteralQuasis(ancestor::TemplateLiteralWithoutQuasis(node)));
     for item in (*((node as *mut u8).add(ancestor::OFFSET_TEMPLATE_LITERAL_QUASIS)
         as *mut Vec<TemplateElement>))
@@ -462,6 +549,8 @@ pub(crate) unsafe fn walk_template_literal<'a, Tr: Traverse<'a>>(
         walk_expression(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TemplateLiteral");
     traverser.exit_template_literal(&mut *node, ctx);
 }
 
@@ -470,7 +559,11 @@ pub(crate) unsafe fn walk_tagged_template_expression<'a, Tr: Traverse<'a>>(
     node: *mut TaggedTemplateExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TaggedTemplateExpression");
     traverser.enter_tagged_template_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TaggedTemplateExpressionTag(
         ancestor::TaggedTemplateExpressionWithoutTag(node),
     ));
@@ -494,6 +587,8 @@ pub(crate) unsafe fn walk_tagged_template_expression<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_instantiation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TaggedTemplateExpression");
     traverser.exit_tagged_template_expression(&mut *node, ctx);
 }
 
@@ -502,6 +597,8 @@ pub(crate) unsafe fn walk_template_element<'a, Tr: Traverse<'a>>(
     node: *mut TemplateElement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TemplateElement");
     traverser.enter_template_element(&mut *node, ctx);
     traverser.exit_template_element(&mut *node, ctx);
 }
@@ -511,7 +608,18 @@ pub(crate) unsafe fn walk_member_expression<'a, Tr: Traverse<'a>>(
     node: *mut MemberExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_member_expression(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_member_expression(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         MemberExpression::ComputedMemberExpression(node) => {
             walk_computed_member_expression(traverser, (&mut **node) as *mut _, ctx)
@@ -531,7 +639,11 @@ pub(crate) unsafe fn walk_computed_member_expression<'a, Tr: Traverse<'a>>(
     node: *mut ComputedMemberExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ComputedMemberExpression");
     traverser.enter_computed_member_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ComputedMemberExpressionObject(
         ancestor::ComputedMemberExpressionWithoutObject(node),
     ));
@@ -549,6 +661,8 @@ pub(crate) unsafe fn walk_computed_member_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ComputedMemberExpression");
     traverser.exit_computed_member_expression(&mut *node, ctx);
 }
 
@@ -557,7 +671,11 @@ pub(crate) unsafe fn walk_static_member_expression<'a, Tr: Traverse<'a>>(
     node: *mut StaticMemberExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("StaticMemberExpression");
     traverser.enter_static_member_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::StaticMemberExpressionObject(
         ancestor::StaticMemberExpressionWithoutObject(node),
     ));
@@ -574,6 +692,8 @@ pub(crate) unsafe fn walk_static_member_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "StaticMemberExpression");
     traverser.exit_static_member_expression(&mut *node, ctx);
 }
 
@@ -582,7 +702,11 @@ pub(crate) unsafe fn walk_private_field_expression<'a, Tr: Traverse<'a>>(
     node: *mut PrivateFieldExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("PrivateFieldExpression");
     traverser.enter_private_field_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::PrivateFieldExpressionObject(
         ancestor::PrivateFieldExpressionWithoutObject(node),
     ));
@@ -599,6 +723,8 @@ pub(crate) unsafe fn walk_private_field_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "PrivateFieldExpression");
     traverser.exit_private_field_expression(&mut *node, ctx);
 }
 
@@ -607,7 +733,11 @@ pub(crate) unsafe fn walk_call_expression<'a, Tr: Traverse<'a>>(
     node: *mut CallExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("CallExpression");
     traverser.enter_call_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::CallExpressionCallee(ancestor::CallExpressionWithoutCallee(node)));
     walk_expression(
         traverser,
@@ -629,6 +759,8 @@ pub(crate) unsafe fn walk_call_expression<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_instantiation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "CallExpression");
     traverser.exit_call_expression(&mut *node, ctx);
 }
 
@@ -637,7 +769,11 @@ pub(crate) unsafe fn walk_new_expression<'a, Tr: Traverse<'a>>(
     node: *mut NewExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("NewExpression");
     traverser.enter_new_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::NewExpressionCallee(ancestor::NewExpressionWithoutCallee(node)));
     walk_expression(
         traverser,
@@ -659,6 +795,8 @@ pub(crate) unsafe fn walk_new_expression<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_instantiation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "NewExpression");
     traverser.exit_new_expression(&mut *node, ctx);
 }
 
@@ -667,7 +805,11 @@ pub(crate) unsafe fn walk_meta_property<'a, Tr: Traverse<'a>>(
     node: *mut MetaProperty<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("MetaProperty");
     traverser.enter_meta_property(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::MetaPropertyMeta(ancestor::MetaPropertyWithoutMeta(node)));
     walk_identifier_name(
         traverser,
@@ -681,6 +823,8 @@ pub(crate) unsafe fn walk_meta_property<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "MetaProperty");
     traverser.exit_meta_property(&mut *node, ctx);
 }
 
@@ -689,7 +833,11 @@ pub(crate) unsafe fn walk_spread_element<'a, Tr: Traverse<'a>>(
     node: *mut SpreadElement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("SpreadElement");
     traverser.enter_spread_element(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::SpreadElementArgument(ancestor::SpreadElementWithoutArgument(node)));
     walk_expression(
         traverser,
@@ -697,6 +845,8 @@ pub(crate) unsafe fn walk_spread_element<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "SpreadElement");
     traverser.exit_spread_element(&mut *node, ctx);
 }
 
@@ -705,7 +855,18 @@ pub(crate) unsafe fn walk_argument<'a, Tr: Traverse<'a>>(
     node: *mut Argument<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_argument(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_argument(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         Argument::SpreadElement(node) => {
             walk_spread_element(traverser, (&mut **node) as *mut _, ctx)
@@ -761,7 +922,11 @@ pub(crate) unsafe fn walk_update_expression<'a, Tr: Traverse<'a>>(
     node: *mut UpdateExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("UpdateExpression");
     traverser.enter_update_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::UpdateExpressionArgument(ancestor::UpdateExpressionWithoutArgument(
         node,
     )));
@@ -772,6 +937,8 @@ pub(crate) unsafe fn walk_update_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "UpdateExpression");
     traverser.exit_update_expression(&mut *node, ctx);
 }
 
@@ -780,7 +947,11 @@ pub(crate) unsafe fn walk_unary_expression<'a, Tr: Traverse<'a>>(
     node: *mut UnaryExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("UnaryExpression");
     traverser.enter_unary_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::UnaryExpressionArgument(ancestor::UnaryExpressionWithoutArgument(
         node,
     )));
@@ -790,6 +961,8 @@ pub(crate) unsafe fn walk_unary_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "UnaryExpression");
     traverser.exit_unary_expression(&mut *node, ctx);
 }
 
@@ -798,7 +971,11 @@ pub(crate) unsafe fn walk_binary_expression<'a, Tr: Traverse<'a>>(
     node: *mut BinaryExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("BinaryExpression");
     traverser.enter_binary_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::BinaryExpressionLeft(ancestor::BinaryExpressionWithoutLeft(node)));
     walk_expression(
         traverser,
@@ -812,6 +989,8 @@ pub(crate) unsafe fn walk_binary_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "BinaryExpression");
     traverser.exit_binary_expression(&mut *node, ctx);
 }
 
@@ -820,7 +999,11 @@ pub(crate) unsafe fn walk_private_in_expression<'a, Tr: Traverse<'a>>(
     node: *mut PrivateInExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("PrivateInExpression");
     traverser.enter_private_in_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::PrivateInExpressionLeft(ancestor::PrivateInExpressionWithoutLeft(
         node,
     )));
@@ -837,6 +1020,8 @@ pub(crate) unsafe fn walk_private_in_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "PrivateInExpression");
     traverser.exit_private_in_expression(&mut *node, ctx);
 }
 
@@ -845,7 +1030,11 @@ pub(crate) unsafe fn walk_logical_expression<'a, Tr: Traverse<'a>>(
     node: *mut LogicalExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("LogicalExpression");
     traverser.enter_logical_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::LogicalExpressionLeft(ancestor::LogicalExpressionWithoutLeft(node)));
     walk_expression(
         traverser,
@@ -859,6 +1048,8 @@ pub(crate) unsafe fn walk_logical_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "LogicalExpression");
     traverser.exit_logical_expression(&mut *node, ctx);
 }
 
@@ -867,7 +1058,11 @@ pub(crate) unsafe fn walk_conditional_expression<'a, Tr: Traverse<'a>>(
     node: *mut ConditionalExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ConditionalExpression");
     traverser.enter_conditional_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ConditionalExpressionTest(
         ancestor::ConditionalExpressionWithoutTest(node),
     ));
@@ -890,6 +1085,8 @@ pub(crate) unsafe fn walk_conditional_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ConditionalExpression");
     traverser.exit_conditional_expression(&mut *node, ctx);
 }
 
@@ -898,7 +1095,11 @@ pub(crate) unsafe fn walk_assignment_expression<'a, Tr: Traverse<'a>>(
     node: *mut AssignmentExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("AssignmentExpression");
     traverser.enter_assignment_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::AssignmentExpressionLeft(ancestor::AssignmentExpressionWithoutLeft(
         node,
     )));
@@ -914,6 +1115,8 @@ pub(crate) unsafe fn walk_assignment_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "AssignmentExpression");
     traverser.exit_assignment_expression(&mut *node, ctx);
 }
 
@@ -922,7 +1125,18 @@ pub(crate) unsafe fn walk_assignment_target<'a, Tr: Traverse<'a>>(
     node: *mut AssignmentTarget<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_assignment_target(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_assignment_target(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         AssignmentTarget::AssignmentTargetIdentifier(_)
         | AssignmentTarget::TSAsExpression(_)
@@ -948,7 +1162,18 @@ pub(crate) unsafe fn walk_simple_assignment_target<'a, Tr: Traverse<'a>>(
     node: *mut SimpleAssignmentTarget<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_simple_assignment_target(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_simple_assignment_target(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         SimpleAssignmentTarget::AssignmentTargetIdentifier(node) => {
             walk_identifier_reference(traverser, (&mut **node) as *mut _, ctx)
@@ -982,7 +1207,18 @@ pub(crate) unsafe fn walk_assignment_target_pattern<'a, Tr: Traverse<'a>>(
     node: *mut AssignmentTargetPattern<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_assignment_target_pattern(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_assignment_target_pattern(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         AssignmentTargetPattern::ArrayAssignmentTarget(node) => {
             walk_array_assignment_target(traverser, (&mut **node) as *mut _, ctx)
@@ -999,7 +1235,11 @@ pub(crate) unsafe fn walk_array_assignment_target<'a, Tr: Traverse<'a>>(
     node: *mut ArrayAssignmentTarget<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ArrayAssignmentTarget");
     traverser.enter_array_assignment_target(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ArrayAssignmentTargetElements(
         ancestor::ArrayAssignmentTargetWithoutElements(node),
     ));
@@ -1017,6 +1257,8 @@ pub(crate) unsafe fn walk_array_assignment_target<'a, Tr: Traverse<'a>>(
         walk_assignment_target_rest(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ArrayAssignmentTarget");
     traverser.exit_array_assignment_target(&mut *node, ctx);
 }
 
@@ -1025,7 +1267,11 @@ pub(crate) unsafe fn walk_object_assignment_target<'a, Tr: Traverse<'a>>(
     node: *mut ObjectAssignmentTarget<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ObjectAssignmentTarget");
     traverser.enter_object_assignment_target(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ObjectAssignmentTargetProperties(
         ancestor::ObjectAssignmentTargetWithoutProperties(node),
     ));
@@ -1043,6 +1289,8 @@ pub(crate) unsafe fn walk_object_assignment_target<'a, Tr: Traverse<'a>>(
         walk_assignment_target_rest(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ObjectAssignmentTarget");
     traverser.exit_object_assignment_target(&mut *node, ctx);
 }
 
@@ -1051,7 +1299,11 @@ pub(crate) unsafe fn walk_assignment_target_rest<'a, Tr: Traverse<'a>>(
     node: *mut AssignmentTargetRest<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("AssignmentTargetRest");
     traverser.enter_assignment_target_rest(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::AssignmentTargetRestTarget(
         ancestor::AssignmentTargetRestWithoutTarget(node),
     ));
@@ -1062,6 +1314,8 @@ pub(crate) unsafe fn walk_assignment_target_rest<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "AssignmentTargetRest");
     traverser.exit_assignment_target_rest(&mut *node, ctx);
 }
 
@@ -1070,7 +1324,18 @@ pub(crate) unsafe fn walk_assignment_target_maybe_default<'a, Tr: Traverse<'a>>(
     node: *mut AssignmentTargetMaybeDefault<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_assignment_target_maybe_default(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_assignment_target_maybe_default(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(node) => {
             walk_assignment_target_with_default(traverser, (&mut **node) as *mut _, ctx)
@@ -1097,7 +1362,11 @@ pub(crate) unsafe fn walk_assignment_target_with_default<'a, Tr: Traverse<'a>>(
     node: *mut AssignmentTargetWithDefault<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("AssignmentTargetWithDefault");
     traverser.enter_assignment_target_with_default(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::AssignmentTargetWithDefaultBinding(
         ancestor::AssignmentTargetWithDefaultWithoutBinding(node),
     ));
@@ -1115,6 +1384,8 @@ pub(crate) unsafe fn walk_assignment_target_with_default<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "AssignmentTargetWithDefault");
     traverser.exit_assignment_target_with_default(&mut *node, ctx);
 }
 
@@ -1123,7 +1394,18 @@ pub(crate) unsafe fn walk_assignment_target_property<'a, Tr: Traverse<'a>>(
     node: *mut AssignmentTargetProperty<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_assignment_target_property(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_assignment_target_property(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(node) => {
             walk_assignment_target_property_identifier(traverser, (&mut **node) as *mut _, ctx)
@@ -1140,7 +1422,11 @@ pub(crate) unsafe fn walk_assignment_target_property_identifier<'a, Tr: Traverse
     node: *mut AssignmentTargetPropertyIdentifier<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("AssignmentTargetPropertyIdentifier");
     traverser.enter_assignment_target_property_identifier(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::AssignmentTargetPropertyIdentifierBinding(
         ancestor::AssignmentTargetPropertyIdentifierWithoutBinding(node),
     ));
@@ -1158,6 +1444,8 @@ pub(crate) unsafe fn walk_assignment_target_property_identifier<'a, Tr: Traverse
         walk_expression(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "AssignmentTargetPropertyIdentifier");
     traverser.exit_assignment_target_property_identifier(&mut *node, ctx);
 }
 
@@ -1166,7 +1454,11 @@ pub(crate) unsafe fn walk_assignment_target_property_property<'a, Tr: Traverse<'
     node: *mut AssignmentTargetPropertyProperty<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("AssignmentTargetPropertyProperty");
     traverser.enter_assignment_target_property_property(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::AssignmentTargetPropertyPropertyName(
         ancestor::AssignmentTargetPropertyPropertyWithoutName(node),
     ));
@@ -1184,6 +1476,8 @@ pub(crate) unsafe fn walk_assignment_target_property_property<'a, Tr: Traverse<'
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "AssignmentTargetPropertyProperty");
     traverser.exit_assignment_target_property_property(&mut *node, ctx);
 }
 
@@ -1192,7 +1486,11 @@ pub(crate) unsafe fn walk_sequence_expression<'a, Tr: Traverse<'a>>(
     node: *mut SequenceExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("SequenceExpression");
     traverser.enter_sequence_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::SequenceExpressionExpressions(
         ancestor::SequenceExpressionWithoutExpressions(node),
     ));
@@ -1203,6 +1501,8 @@ pub(crate) unsafe fn walk_sequence_expression<'a, Tr: Traverse<'a>>(
         walk_expression(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "SequenceExpression");
     traverser.exit_sequence_expression(&mut *node, ctx);
 }
 
@@ -1211,6 +1511,8 @@ pub(crate) unsafe fn walk_super<'a, Tr: Traverse<'a>>(
     node: *mut Super,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("Super");
     traverser.enter_super(&mut *node, ctx);
     traverser.exit_super(&mut *node, ctx);
 }
@@ -1220,7 +1522,11 @@ pub(crate) unsafe fn walk_await_expression<'a, Tr: Traverse<'a>>(
     node: *mut AwaitExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("AwaitExpression");
     traverser.enter_await_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::AwaitExpressionArgument(ancestor::AwaitExpressionWithoutArgument(
         node,
     )));
@@ -1230,6 +1536,8 @@ pub(crate) unsafe fn walk_await_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "AwaitExpression");
     traverser.exit_await_expression(&mut *node, ctx);
 }
 
@@ -1238,7 +1546,11 @@ pub(crate) unsafe fn walk_chain_expression<'a, Tr: Traverse<'a>>(
     node: *mut ChainExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ChainExpression");
     traverser.enter_chain_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ChainExpressionExpression(
         ancestor::ChainExpressionWithoutExpression(node),
     ));
@@ -1248,6 +1560,8 @@ pub(crate) unsafe fn walk_chain_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ChainExpression");
     traverser.exit_chain_expression(&mut *node, ctx);
 }
 
@@ -1256,7 +1570,18 @@ pub(crate) unsafe fn walk_chain_element<'a, Tr: Traverse<'a>>(
     node: *mut ChainElement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_chain_element(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_chain_element(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         ChainElement::CallExpression(node) => {
             walk_call_expression(traverser, (&mut **node) as *mut _, ctx)
@@ -1275,7 +1600,11 @@ pub(crate) unsafe fn walk_parenthesized_expression<'a, Tr: Traverse<'a>>(
     node: *mut ParenthesizedExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ParenthesizedExpression");
     traverser.enter_parenthesized_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ParenthesizedExpressionExpression(
         ancestor::ParenthesizedExpressionWithoutExpression(node),
     ));
@@ -1286,6 +1615,8 @@ pub(crate) unsafe fn walk_parenthesized_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ParenthesizedExpression");
     traverser.exit_parenthesized_expression(&mut *node, ctx);
 }
 
@@ -1294,7 +1625,18 @@ pub(crate) unsafe fn walk_statement<'a, Tr: Traverse<'a>>(
     node: *mut Statement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_statement(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_statement(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         Statement::BlockStatement(node) => {
             walk_block_statement(traverser, (&mut **node) as *mut _, ctx)
@@ -1376,7 +1718,11 @@ pub(crate) unsafe fn walk_directive<'a, Tr: Traverse<'a>>(
     node: *mut Directive<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("Directive");
     traverser.enter_directive(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::DirectiveExpression(ancestor::DirectiveWithoutExpression(node)));
     walk_string_literal(
         traverser,
@@ -1384,6 +1730,8 @@ pub(crate) unsafe fn walk_directive<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "Directive");
     traverser.exit_directive(&mut *node, ctx);
 }
 
@@ -1392,6 +1740,8 @@ pub(crate) unsafe fn walk_hashbang<'a, Tr: Traverse<'a>>(
     node: *mut Hashbang<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("Hashbang");
     traverser.enter_hashbang(&mut *node, ctx);
     traverser.exit_hashbang(&mut *node, ctx);
 }
@@ -1409,7 +1759,11 @@ pub(crate) unsafe fn walk_block_statement<'a, Tr: Traverse<'a>>(
         previous_scope_id = Some(ctx.current_scope_id());
         ctx.set_current_scope_id(scope_id);
     }
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("BlockStatement");
     traverser.enter_block_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::BlockStatementBody(ancestor::BlockStatementWithoutBody(node)));
     walk_statements(
         traverser,
@@ -1417,6 +1771,8 @@ pub(crate) unsafe fn walk_block_statement<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "BlockStatement");
     traverser.exit_block_statement(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -1428,7 +1784,18 @@ pub(crate) unsafe fn walk_declaration<'a, Tr: Traverse<'a>>(
     node: *mut Declaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_declaration(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_declaration(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         Declaration::VariableDeclaration(node) => {
             walk_variable_declaration(traverser, (&mut **node) as *mut _, ctx)
@@ -1464,7 +1831,11 @@ pub(crate) unsafe fn walk_variable_declaration<'a, Tr: Traverse<'a>>(
     node: *mut VariableDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("VariableDeclaration");
     traverser.enter_variable_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::VariableDeclarationDeclarations(
         ancestor::VariableDeclarationWithoutDeclarations(node),
     ));
@@ -1475,6 +1846,8 @@ pub(crate) unsafe fn walk_variable_declaration<'a, Tr: Traverse<'a>>(
         walk_variable_declarator(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "VariableDeclaration");
     traverser.exit_variable_declaration(&mut *node, ctx);
 }
 
@@ -1483,7 +1856,11 @@ pub(crate) unsafe fn walk_variable_declarator<'a, Tr: Traverse<'a>>(
     node: *mut VariableDeclarator<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("VariableDeclarator");
     traverser.enter_variable_declarator(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::VariableDeclaratorId(ancestor::VariableDeclaratorWithoutId(node)));
     walk_binding_pattern(
         traverser,
@@ -1497,6 +1874,8 @@ pub(crate) unsafe fn walk_variable_declarator<'a, Tr: Traverse<'a>>(
         walk_expression(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "VariableDeclarator");
     traverser.exit_variable_declarator(&mut *node, ctx);
 }
 
@@ -1505,7 +1884,11 @@ pub(crate) unsafe fn walk_using_declaration<'a, Tr: Traverse<'a>>(
     node: *mut UsingDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("UsingDeclaration");
     traverser.enter_using_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::UsingDeclarationDeclarations(
         ancestor::UsingDeclarationWithoutDeclarations(node),
     ));
@@ -1516,6 +1899,8 @@ pub(crate) unsafe fn walk_using_declaration<'a, Tr: Traverse<'a>>(
         walk_variable_declarator(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "UsingDeclaration");
     traverser.exit_using_declaration(&mut *node, ctx);
 }
 
@@ -1524,6 +1909,8 @@ pub(crate) unsafe fn walk_empty_statement<'a, Tr: Traverse<'a>>(
     node: *mut EmptyStatement,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("EmptyStatement");
     traverser.enter_empty_statement(&mut *node, ctx);
     traverser.exit_empty_statement(&mut *node, ctx);
 }
@@ -1533,7 +1920,11 @@ pub(crate) unsafe fn walk_expression_statement<'a, Tr: Traverse<'a>>(
     node: *mut ExpressionStatement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ExpressionStatement");
     traverser.enter_expression_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ExpressionStatementExpression(
         ancestor::ExpressionStatementWithoutExpression(node),
     ));
@@ -1543,6 +1934,8 @@ pub(crate) unsafe fn walk_expression_statement<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ExpressionStatement");
     traverser.exit_expression_statement(&mut *node, ctx);
 }
 
@@ -1551,7 +1944,11 @@ pub(crate) unsafe fn walk_if_statement<'a, Tr: Traverse<'a>>(
     node: *mut IfStatement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("IfStatement");
     traverser.enter_if_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::IfStatementTest(ancestor::IfStatementWithoutTest(node)));
     walk_expression(
         traverser,
@@ -1571,6 +1968,8 @@ pub(crate) unsafe fn walk_if_statement<'a, Tr: Traverse<'a>>(
         walk_statement(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "IfStatement");
     traverser.exit_if_statement(&mut *node, ctx);
 }
 
@@ -1579,7 +1978,11 @@ pub(crate) unsafe fn walk_do_while_statement<'a, Tr: Traverse<'a>>(
     node: *mut DoWhileStatement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("DoWhileStatement");
     traverser.enter_do_while_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::DoWhileStatementBody(ancestor::DoWhileStatementWithoutBody(node)));
     walk_statement(
         traverser,
@@ -1593,6 +1996,8 @@ pub(crate) unsafe fn walk_do_while_statement<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "DoWhileStatement");
     traverser.exit_do_while_statement(&mut *node, ctx);
 }
 
@@ -1601,7 +2006,11 @@ pub(crate) unsafe fn walk_while_statement<'a, Tr: Traverse<'a>>(
     node: *mut WhileStatement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("WhileStatement");
     traverser.enter_while_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::WhileStatementTest(ancestor::WhileStatementWithoutTest(node)));
     walk_expression(
         traverser,
@@ -1615,6 +2024,8 @@ pub(crate) unsafe fn walk_while_statement<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "WhileStatement");
     traverser.exit_while_statement(&mut *node, ctx);
 }
 
@@ -1631,7 +2042,11 @@ pub(crate) unsafe fn walk_for_statement<'a, Tr: Traverse<'a>>(
         previous_scope_id = Some(ctx.current_scope_id());
         ctx.set_current_scope_id(scope_id);
     }
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ForStatement");
     traverser.enter_for_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ForStatementInit(ancestor::ForStatementWithoutInit(node)));
     if let Some(field) = &mut *((node as *mut u8).add(ancestor::OFFSET_FOR_STATEMENT_INIT)
         as *mut Option<ForStatementInit>)
@@ -1657,6 +2072,8 @@ pub(crate) unsafe fn walk_for_statement<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ForStatement");
     traverser.exit_for_statement(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -1668,7 +2085,18 @@ pub(crate) unsafe fn walk_for_statement_init<'a, Tr: Traverse<'a>>(
     node: *mut ForStatementInit<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_for_statement_init(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_for_statement_init(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         ForStatementInit::VariableDeclaration(node) => {
             walk_variable_declaration(traverser, (&mut **node) as *mut _, ctx)
@@ -1737,7 +2165,11 @@ pub(crate) unsafe fn walk_for_in_statement<'a, Tr: Traverse<'a>>(
         previous_scope_id = Some(ctx.current_scope_id());
         ctx.set_current_scope_id(scope_id);
     }
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ForInStatement");
     traverser.enter_for_in_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ForInStatementLeft(ancestor::ForInStatementWithoutLeft(node)));
     walk_for_statement_left(
         traverser,
@@ -1757,6 +2189,8 @@ pub(crate) unsafe fn walk_for_in_statement<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ForInStatement");
     traverser.exit_for_in_statement(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -1776,7 +2210,11 @@ pub(crate) unsafe fn walk_for_of_statement<'a, Tr: Traverse<'a>>(
         previous_scope_id = Some(ctx.current_scope_id());
         ctx.set_current_scope_id(scope_id);
     }
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ForOfStatement");
     traverser.enter_for_of_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ForOfStatementLeft(ancestor::ForOfStatementWithoutLeft(node)));
     walk_for_statement_left(
         traverser,
@@ -1796,6 +2234,8 @@ pub(crate) unsafe fn walk_for_of_statement<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ForOfStatement");
     traverser.exit_for_of_statement(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -1807,7 +2247,18 @@ pub(crate) unsafe fn walk_for_statement_left<'a, Tr: Traverse<'a>>(
     node: *mut ForStatementLeft<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_for_statement_left(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_for_statement_left(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         ForStatementLeft::VariableDeclaration(node) => {
             walk_variable_declaration(traverser, (&mut **node) as *mut _, ctx)
@@ -1837,7 +2288,11 @@ pub(crate) unsafe fn walk_continue_statement<'a, Tr: Traverse<'a>>(
     node: *mut ContinueStatement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ContinueStatement");
     traverser.enter_continue_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ContinueStatementLabel(ancestor::ContinueStatementWithoutLabel(node)));
     if let Some(field) = &mut *((node as *mut u8).add(ancestor::OFFSET_CONTINUE_STATEMENT_LABEL)
         as *mut Option<LabelIdentifier>)
@@ -1845,6 +2300,8 @@ pub(crate) unsafe fn walk_continue_statement<'a, Tr: Traverse<'a>>(
         walk_label_identifier(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ContinueStatement");
     traverser.exit_continue_statement(&mut *node, ctx);
 }
 
@@ -1853,7 +2310,11 @@ pub(crate) unsafe fn walk_break_statement<'a, Tr: Traverse<'a>>(
     node: *mut BreakStatement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("BreakStatement");
     traverser.enter_break_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::BreakStatementLabel(ancestor::BreakStatementWithoutLabel(node)));
     if let Some(field) = &mut *((node as *mut u8).add(ancestor::OFFSET_BREAK_STATEMENT_LABEL)
         as *mut Option<LabelIdentifier>)
@@ -1861,6 +2322,8 @@ pub(crate) unsafe fn walk_break_statement<'a, Tr: Traverse<'a>>(
         walk_label_identifier(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "BreakStatement");
     traverser.exit_break_statement(&mut *node, ctx);
 }
 
@@ -1869,7 +2332,11 @@ pub(crate) unsafe fn walk_return_statement<'a, Tr: Traverse<'a>>(
     node: *mut ReturnStatement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ReturnStatement");
     traverser.enter_return_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ReturnStatementArgument(ancestor::ReturnStatementWithoutArgument(
         node,
     )));
@@ -1879,6 +2346,8 @@ pub(crate) unsafe fn walk_return_statement<'a, Tr: Traverse<'a>>(
         walk_expression(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ReturnStatement");
     traverser.exit_return_statement(&mut *node, ctx);
 }
 
@@ -1887,7 +2356,11 @@ pub(crate) unsafe fn walk_with_statement<'a, Tr: Traverse<'a>>(
     node: *mut WithStatement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("WithStatement");
     traverser.enter_with_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::WithStatementObject(ancestor::WithStatementWithoutObject(node)));
     walk_expression(
         traverser,
@@ -1901,6 +2374,8 @@ pub(crate) unsafe fn walk_with_statement<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "WithStatement");
     traverser.exit_with_statement(&mut *node, ctx);
 }
 
@@ -1909,7 +2384,11 @@ pub(crate) unsafe fn walk_switch_statement<'a, Tr: Traverse<'a>>(
     node: *mut SwitchStatement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("SwitchStatement");
     traverser.enter_switch_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::SwitchStatementDiscriminant(
         ancestor::SwitchStatementWithoutDiscriminant(node),
     ));
@@ -1934,6 +2413,8 @@ pub(crate) unsafe fn walk_switch_statement<'a, Tr: Traverse<'a>>(
         walk_switch_case(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "SwitchStatement");
     traverser.exit_switch_statement(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -1945,7 +2426,11 @@ pub(crate) unsafe fn walk_switch_case<'a, Tr: Traverse<'a>>(
     node: *mut SwitchCase<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("SwitchCase");
     traverser.enter_switch_case(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::SwitchCaseTest(ancestor::SwitchCaseWithoutTest(node)));
     if let Some(field) =
         &mut *((node as *mut u8).add(ancestor::OFFSET_SWITCH_CASE_TEST) as *mut Option<Expression>)
@@ -1959,6 +2444,8 @@ pub(crate) unsafe fn walk_switch_case<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "SwitchCase");
     traverser.exit_switch_case(&mut *node, ctx);
 }
 
@@ -1967,7 +2454,11 @@ pub(crate) unsafe fn walk_labeled_statement<'a, Tr: Traverse<'a>>(
     node: *mut LabeledStatement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("LabeledStatement");
     traverser.enter_labeled_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::LabeledStatementLabel(ancestor::LabeledStatementWithoutLabel(node)));
     walk_label_identifier(
         traverser,
@@ -1981,6 +2472,8 @@ pub(crate) unsafe fn walk_labeled_statement<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "LabeledStatement");
     traverser.exit_labeled_statement(&mut *node, ctx);
 }
 
@@ -1989,7 +2482,11 @@ pub(crate) unsafe fn walk_throw_statement<'a, Tr: Traverse<'a>>(
     node: *mut ThrowStatement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ThrowStatement");
     traverser.enter_throw_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ThrowStatementArgument(ancestor::ThrowStatementWithoutArgument(node)));
     walk_expression(
         traverser,
@@ -1997,6 +2494,8 @@ pub(crate) unsafe fn walk_throw_statement<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ThrowStatement");
     traverser.exit_throw_statement(&mut *node, ctx);
 }
 
@@ -2005,7 +2504,11 @@ pub(crate) unsafe fn walk_try_statement<'a, Tr: Traverse<'a>>(
     node: *mut TryStatement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TryStatement");
     traverser.enter_try_statement(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TryStatementBlock(ancestor::TryStatementWithoutBlock(node)));
     walk_block_statement(
         traverser,
@@ -2026,6 +2529,8 @@ pub(crate) unsafe fn walk_try_statement<'a, Tr: Traverse<'a>>(
         walk_block_statement(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TryStatement");
     traverser.exit_try_statement(&mut *node, ctx);
 }
 
@@ -2042,7 +2547,11 @@ pub(crate) unsafe fn walk_catch_clause<'a, Tr: Traverse<'a>>(
         previous_scope_id = Some(ctx.current_scope_id());
         ctx.set_current_scope_id(scope_id);
     }
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("CatchClause");
     traverser.enter_catch_clause(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::CatchClauseParam(ancestor::CatchClauseWithoutParam(node)));
     if let Some(field) = &mut *((node as *mut u8).add(ancestor::OFFSET_CATCH_CLAUSE_PARAM)
         as *mut Option<CatchParameter>)
@@ -2057,6 +2566,8 @@ pub(crate) unsafe fn walk_catch_clause<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "CatchClause");
     traverser.exit_catch_clause(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -2068,7 +2579,11 @@ pub(crate) unsafe fn walk_catch_parameter<'a, Tr: Traverse<'a>>(
     node: *mut CatchParameter<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("CatchParameter");
     traverser.enter_catch_parameter(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::CatchParameterPattern(ancestor::CatchParameterWithoutPattern(node)));
     walk_binding_pattern(
         traverser,
@@ -2076,6 +2591,8 @@ pub(crate) unsafe fn walk_catch_parameter<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "CatchParameter");
     traverser.exit_catch_parameter(&mut *node, ctx);
 }
 
@@ -2084,6 +2601,8 @@ pub(crate) unsafe fn walk_debugger_statement<'a, Tr: Traverse<'a>>(
     node: *mut DebuggerStatement,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("DebuggerStatement");
     traverser.enter_debugger_statement(&mut *node, ctx);
     traverser.exit_debugger_statement(&mut *node, ctx);
 }
@@ -2093,7 +2612,11 @@ pub(crate) unsafe fn walk_binding_pattern<'a, Tr: Traverse<'a>>(
     node: *mut BindingPattern<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("BindingPattern");
     traverser.enter_binding_pattern(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::BindingPatternKind(ancestor::BindingPatternWithoutKind(node)));
     walk_binding_pattern_kind(
         traverser,
@@ -2108,6 +2631,8 @@ pub(crate) unsafe fn walk_binding_pattern<'a, Tr: Traverse<'a>>(
         walk_ts_type_annotation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "BindingPattern");
     traverser.exit_binding_pattern(&mut *node, ctx);
 }
 
@@ -2116,7 +2641,18 @@ pub(crate) unsafe fn walk_binding_pattern_kind<'a, Tr: Traverse<'a>>(
     node: *mut BindingPatternKind<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_binding_pattern_kind(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_binding_pattern_kind(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         BindingPatternKind::BindingIdentifier(node) => {
             walk_binding_identifier(traverser, (&mut **node) as *mut _, ctx)
@@ -2139,7 +2675,11 @@ pub(crate) unsafe fn walk_assignment_pattern<'a, Tr: Traverse<'a>>(
     node: *mut AssignmentPattern<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("AssignmentPattern");
     traverser.enter_assignment_pattern(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::AssignmentPatternLeft(ancestor::AssignmentPatternWithoutLeft(node)));
     walk_binding_pattern(
         traverser,
@@ -2153,6 +2693,8 @@ pub(crate) unsafe fn walk_assignment_pattern<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "AssignmentPattern");
     traverser.exit_assignment_pattern(&mut *node, ctx);
 }
 
@@ -2161,7 +2703,11 @@ pub(crate) unsafe fn walk_object_pattern<'a, Tr: Traverse<'a>>(
     node: *mut ObjectPattern<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ObjectPattern");
     traverser.enter_object_pattern(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ObjectPatternProperties(ancestor::ObjectPatternWithoutProperties(
         node,
     )));
@@ -2178,6 +2724,8 @@ pub(crate) unsafe fn walk_object_pattern<'a, Tr: Traverse<'a>>(
         walk_binding_rest_element(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ObjectPattern");
     traverser.exit_object_pattern(&mut *node, ctx);
 }
 
@@ -2186,7 +2734,11 @@ pub(crate) unsafe fn walk_binding_property<'a, Tr: Traverse<'a>>(
     node: *mut BindingProperty<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("BindingProperty");
     traverser.enter_binding_property(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::BindingPropertyKey(ancestor::BindingPropertyWithoutKey(node)));
     walk_property_key(
         traverser,
@@ -2200,6 +2752,8 @@ pub(crate) unsafe fn walk_binding_property<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "BindingProperty");
     traverser.exit_binding_property(&mut *node, ctx);
 }
 
@@ -2208,7 +2762,11 @@ pub(crate) unsafe fn walk_array_pattern<'a, Tr: Traverse<'a>>(
     node: *mut ArrayPattern<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ArrayPattern");
     traverser.enter_array_pattern(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ArrayPatternElements(ancestor::ArrayPatternWithoutElements(node)));
     for item in (*((node as *mut u8).add(ancestor::OFFSET_ARRAY_PATTERN_ELEMENTS)
         as *mut Vec<Option<BindingPattern>>))
@@ -2224,6 +2782,8 @@ pub(crate) unsafe fn walk_array_pattern<'a, Tr: Traverse<'a>>(
         walk_binding_rest_element(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ArrayPattern");
     traverser.exit_array_pattern(&mut *node, ctx);
 }
 
@@ -2232,7 +2792,11 @@ pub(crate) unsafe fn walk_binding_rest_element<'a, Tr: Traverse<'a>>(
     node: *mut BindingRestElement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("BindingRestElement");
     traverser.enter_binding_rest_element(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::BindingRestElementArgument(
         ancestor::BindingRestElementWithoutArgument(node),
     ));
@@ -2243,6 +2807,8 @@ pub(crate) unsafe fn walk_binding_rest_element<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "BindingRestElement");
     traverser.exit_binding_rest_element(&mut *node, ctx);
 }
 
@@ -2259,7 +2825,11 @@ pub(crate) unsafe fn walk_function<'a, Tr: Traverse<'a>>(
         previous_scope_id = Some(ctx.current_scope_id());
         ctx.set_current_scope_id(scope_id);
     }
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("Function");
     traverser.enter_function(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::FunctionId(ancestor::FunctionWithoutId(node)));
     if let Some(field) = &mut *((node as *mut u8).add(ancestor::OFFSET_FUNCTION_ID)
         as *mut Option<BindingIdentifier>)
@@ -2298,6 +2868,8 @@ pub(crate) unsafe fn walk_function<'a, Tr: Traverse<'a>>(
         walk_ts_type_annotation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "Function");
     traverser.exit_function(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -2309,7 +2881,11 @@ pub(crate) unsafe fn walk_formal_parameters<'a, Tr: Traverse<'a>>(
     node: *mut FormalParameters<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("FormalParameters");
     traverser.enter_formal_parameters(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::FormalParametersItems(ancestor::FormalParametersWithoutItems(node)));
     for item in (*((node as *mut u8).add(ancestor::OFFSET_FORMAL_PARAMETERS_ITEMS)
         as *mut Vec<FormalParameter>))
@@ -2324,6 +2900,8 @@ pub(crate) unsafe fn walk_formal_parameters<'a, Tr: Traverse<'a>>(
         walk_binding_rest_element(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "FormalParameters");
     traverser.exit_formal_parameters(&mut *node, ctx);
 }
 
@@ -2332,7 +2910,11 @@ pub(crate) unsafe fn walk_formal_parameter<'a, Tr: Traverse<'a>>(
     node: *mut FormalParameter<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("FormalParameter");
     traverser.enter_formal_parameter(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::FormalParameterPattern(ancestor::FormalParameterWithoutPattern(node)));
     walk_binding_pattern(
         traverser,
@@ -2347,6 +2929,8 @@ pub(crate) unsafe fn walk_formal_parameter<'a, Tr: Traverse<'a>>(
         walk_decorator(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "FormalParameter");
     traverser.exit_formal_parameter(&mut *node, ctx);
 }
 
@@ -2355,7 +2939,11 @@ pub(crate) unsafe fn walk_function_body<'a, Tr: Traverse<'a>>(
     node: *mut FunctionBody<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("FunctionBody");
     traverser.enter_function_body(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::FunctionBodyDirectives(ancestor::FunctionBodyWithoutDirectives(node)));
     for item in (*((node as *mut u8).add(ancestor::OFFSET_FUNCTION_BODY_DIRECTIVES)
         as *mut Vec<Directive>))
@@ -2370,7 +2958,13 @@ pub(crate) unsafe fn walk_function_body<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "FunctionBody");
     traverser.exit_function_body(&mut *node, ctx);
+    ctx.exit_hoist_scope(
+        &mut *((node as *mut u8).add(ancestor::OFFSET_FUNCTION_BODY_STATEMENTS)
+            as *mut Vec<Statement>),
+    );
 }
 
 pub(crate) unsafe fn walk_arrow_function_expression<'a, Tr: Traverse<'a>>(
@@ -2387,7 +2981,11 @@ pub(crate) unsafe fn walk_arrow_function_expression<'a, Tr: Traverse<'a>>(
         previous_scope_id = Some(ctx.current_scope_id());
         ctx.set_current_scope_id(scope_id);
     }
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ArrowFunctionExpression");
     traverser.enter_arrow_function_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ArrowFunctionExpressionParams(
         ancestor::ArrowFunctionExpressionWithoutParams(node),
     ));
@@ -2419,6 +3017,8 @@ pub(crate) unsafe fn walk_arrow_function_expression<'a, Tr: Traverse<'a>>(
         walk_ts_type_annotation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ArrowFunctionExpression");
     traverser.exit_arrow_function_expression(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -2430,7 +3030,11 @@ pub(crate) unsafe fn walk_yield_expression<'a, Tr: Traverse<'a>>(
     node: *mut YieldExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("YieldExpression");
     traverser.enter_yield_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::YieldExpressionArgument(ancestor::YieldExpressionWithoutArgument(
         node,
     )));
@@ -2440,6 +3044,8 @@ pub(crate) unsafe fn walk_yield_expression<'a, Tr: Traverse<'a>>(
         walk_expression(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "YieldExpression");
     traverser.exit_yield_expression(&mut *node, ctx);
 }
 
@@ -2448,7 +3054,11 @@ pub(crate) unsafe fn walk_class<'a, Tr: Traverse<'a>>(
     node: *mut Class<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("Class");
     traverser.enter_class(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ClassDecorators(ancestor::ClassWithoutDecorators(node)));
     for item in (*((node as *mut u8).add(ancestor::OFFSET_CLASS_DECORATORS) as *mut Vec<Decorator>))
         .iter_mut()
@@ -2503,6 +3113,8 @@ pub(crate) unsafe fn walk_class<'a, Tr: Traverse<'a>>(
         }
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "Class");
     traverser.exit_class(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -2514,7 +3126,11 @@ pub(crate) unsafe fn walk_class_body<'a, Tr: Traverse<'a>>(
     node: *mut ClassBody<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ClassBody");
     traverser.enter_class_body(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ClassBodyBody(ancestor::ClassBodyWithoutBody(node)));
     for item in (*((node as *mut u8).add(ancestor::OFFSET_CLASS_BODY_BODY)
         as *mut Vec<ClassElement>))
@@ -2523,6 +3139,8 @@ pub(crate) unsafe fn walk_class_body<'a, Tr: Traverse<'a>>(
         walk_class_element(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ClassBody");
     traverser.exit_class_body(&mut *node, ctx);
 }
 
@@ -2531,7 +3149,18 @@ pub(crate) unsafe fn walk_class_element<'a, Tr: Traverse<'a>>(
     node: *mut ClassElement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_class_element(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_class_element(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         ClassElement::StaticBlock(node) => {
             walk_static_block(traverser, (&mut **node) as *mut _, ctx)
@@ -2557,7 +3186,11 @@ pub(crate) unsafe fn walk_method_definition<'a, Tr: Traverse<'a>>(
     node: *mut MethodDefinition<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("MethodDefinition");
     traverser.enter_method_definition(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::MethodDefinitionDecorators(
         ancestor::MethodDefinitionWithoutDecorators(node),
     ));
@@ -2581,6 +3214,8 @@ pub(crate) unsafe fn walk_method_definition<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "MethodDefinition");
     traverser.exit_method_definition(&mut *node, ctx);
 }
 
@@ -2589,7 +3224,11 @@ pub(crate) unsafe fn walk_property_definition<'a, Tr: Traverse<'a>>(
     node: *mut PropertyDefinition<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("PropertyDefinition");
     traverser.enter_property_definition(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::PropertyDefinitionKey(ancestor::PropertyDefinitionWithoutKey(node)));
     walk_property_key(
         traverser,
@@ -2617,6 +3256,8 @@ pub(crate) unsafe fn walk_property_definition<'a, Tr: Traverse<'a>>(
         walk_decorator(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "PropertyDefinition");
     traverser.exit_property_definition(&mut *node, ctx);
 }
 
@@ -2625,6 +3266,8 @@ pub(crate) unsafe fn walk_private_identifier<'a, Tr: Traverse<'a>>(
     node: *mut PrivateIdentifier<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("PrivateIdentifier");
     traverser.enter_private_identifier(&mut *node, ctx);
     traverser.exit_private_identifier(&mut *node, ctx);
 }
@@ -2642,7 +3285,11 @@ pub(crate) unsafe fn walk_static_block<'a, Tr: Traverse<'a>>(
         previous_scope_id = Some(ctx.current_scope_id());
         ctx.set_current_scope_id(scope_id);
     }
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("StaticBlock");
     traverser.enter_static_block(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::StaticBlockBody(ancestor::StaticBlockWithoutBody(node)));
     walk_statements(
         traverser,
@@ -2650,6 +3297,8 @@ pub(crate) unsafe fn walk_static_block<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "StaticBlock");
     traverser.exit_static_block(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -2661,7 +3310,18 @@ pub(crate) unsafe fn walk_module_declaration<'a, Tr: Traverse<'a>>(
     node: *mut ModuleDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_module_declaration(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_module_declaration(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         ModuleDeclaration::ImportDeclaration(node) => {
             walk_import_declaration(traverser, (&mut **node) as *mut _, ctx)
@@ -2690,7 +3350,11 @@ pub(crate) unsafe fn walk_accessor_property<'a, Tr: Traverse<'a>>(
     node: *mut AccessorProperty<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("AccessorProperty");
     traverser.enter_accessor_property(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::AccessorPropertyKey(ancestor::AccessorPropertyWithoutKey(node)));
     walk_property_key(
         traverser,
@@ -2711,6 +3375,8 @@ pub(crate) unsafe fn walk_accessor_property<'a, Tr: Traverse<'a>>(
         walk_decorator(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "AccessorProperty");
     traverser.exit_accessor_property(&mut *node, ctx);
 }
 
@@ -2719,7 +3385,11 @@ pub(crate) unsafe fn walk_import_expression<'a, Tr: Traverse<'a>>(
     node: *mut ImportExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ImportExpression");
     traverser.enter_import_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ImportExpressionSource(ancestor::ImportExpressionWithoutSource(node)));
     walk_expression(
         traverser,
@@ -2734,6 +3404,8 @@ pub(crate) unsafe fn walk_import_expression<'a, Tr: Traverse<'a>>(
         walk_expression(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ImportExpression");
     traverser.exit_import_expression(&mut *node, ctx);
 }
 
@@ -2742,7 +3414,11 @@ pub(crate) unsafe fn walk_import_declaration<'a, Tr: Traverse<'a>>(
     node: *mut ImportDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ImportDeclaration");
     traverser.enter_import_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ImportDeclarationSpecifiers(
         ancestor::ImportDeclarationWithoutSpecifiers(node),
     ));
@@ -2768,6 +3444,8 @@ pub(crate) unsafe fn walk_import_declaration<'a, Tr: Traverse<'a>>(
         walk_with_clause(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ImportDeclaration");
     traverser.exit_import_declaration(&mut *node, ctx);
 }
 
@@ -2776,7 +3454,18 @@ pub(crate) unsafe fn walk_import_declaration_specifier<'a, Tr: Traverse<'a>>(
     node: *mut ImportDeclarationSpecifier<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_import_declaration_specifier(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_import_declaration_specifier(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         ImportDeclarationSpecifier::ImportSpecifier(node) => {
             walk_import_specifier(traverser, (&mut **node) as *mut _, ctx)
@@ -2796,7 +3485,11 @@ pub(crate) unsafe fn walk_import_specifier<'a, Tr: Traverse<'a>>(
     node: *mut ImportSpecifier<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ImportSpecifier");
     traverser.enter_import_specifier(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ImportSpecifierImported(ancestor::ImportSpecifierWithoutImported(
         node,
     )));
@@ -2812,6 +3505,8 @@ pub(crate) unsafe fn walk_import_specifier<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ImportSpecifier");
     traverser.exit_import_specifier(&mut *node, ctx);
 }
 
@@ -2820,7 +3515,11 @@ pub(crate) unsafe fn walk_import_default_specifier<'a, Tr: Traverse<'a>>(
     node: *mut ImportDefaultSpecifier<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ImportDefaultSpecifier");
     traverser.enter_import_default_specifier(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ImportDefaultSpecifierLocal(
         ancestor::ImportDefaultSpecifierWithoutLocal(node),
     ));
@@ -2831,6 +3530,8 @@ pub(crate) unsafe fn walk_import_default_specifier<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ImportDefaultSpecifier");
     traverser.exit_import_default_specifier(&mut *node, ctx);
 }
 
@@ -2839,7 +3540,11 @@ pub(crate) unsafe fn walk_import_namespace_specifier<'a, Tr: Traverse<'a>>(
     node: *mut ImportNamespaceSpecifier<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ImportNamespaceSpecifier");
     traverser.enter_import_namespace_specifier(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ImportNamespaceSpecifierLocal(
         ancestor::ImportNamespaceSpecifierWithoutLocal(node),
     ));
@@ -2850,6 +3555,8 @@ pub(crate) unsafe fn walk_import_namespace_specifier<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ImportNamespaceSpecifier");
     traverser.exit_import_namespace_specifier(&mut *node, ctx);
 }
 
@@ -2858,7 +3565,11 @@ pub(crate) unsafe fn walk_with_clause<'a, Tr: Traverse<'a>>(
     node: *mut WithClause<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("WithClause");
     traverser.enter_with_clause(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::WithClauseAttributesKeyword(
         ancestor::WithClauseWithoutAttributesKeyword(node),
     ));
@@ -2876,6 +3587,8 @@ pub(crate) unsafe fn walk_with_clause<'a, Tr: Traverse<'a>>(
         walk_import_attribute(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "WithClause");
     traverser.exit_with_clause(&mut *node, ctx);
 }
 
@@ -2884,7 +3597,11 @@ pub(crate) unsafe fn walk_import_attribute<'a, Tr: Traverse<'a>>(
     node: *mut ImportAttribute<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ImportAttribute");
     traverser.enter_import_attribute(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ImportAttributeKey(ancestor::ImportAttributeWithoutKey(node)));
     walk_import_attribute_key(
         traverser,
@@ -2898,6 +3615,8 @@ pub(crate) unsafe fn walk_import_attribute<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ImportAttribute");
     traverser.exit_import_attribute(&mut *node, ctx);
 }
 
@@ -2906,7 +3625,18 @@ pub(crate) unsafe fn walk_import_attribute_key<'a, Tr: Traverse<'a>>(
     node: *mut ImportAttributeKey<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_import_attribute_key(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_import_attribute_key(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         ImportAttributeKey::Identifier(node) => {
             walk_identifier_name(traverser, node as *mut _, ctx)
@@ -2923,7 +3653,11 @@ pub(crate) unsafe fn walk_export_named_declaration<'a, Tr: Traverse<'a>>(
     node: *mut ExportNamedDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ExportNamedDeclaration");
     traverser.enter_export_named_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ExportNamedDeclarationDeclaration(
         ancestor::ExportNamedDeclarationWithoutDeclaration(node),
     ));
@@ -2955,6 +3689,8 @@ pub(crate) unsafe fn walk_export_named_declaration<'a, Tr: Traverse<'a>>(
         walk_with_clause(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ExportNamedDeclaration");
     traverser.exit_export_named_declaration(&mut *node, ctx);
 }
 
@@ -2963,7 +3699,11 @@ pub(crate) unsafe fn walk_export_default_declaration<'a, Tr: Traverse<'a>>(
     node: *mut ExportDefaultDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ExportDefaultDeclaration");
     traverser.enter_export_default_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ExportDefaultDeclarationDeclaration(
         ancestor::ExportDefaultDeclarationWithoutDeclaration(node),
     ));
@@ -2981,6 +3721,8 @@ pub(crate) unsafe fn walk_export_default_declaration<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ExportDefaultDeclaration");
     traverser.exit_export_default_declaration(&mut *node, ctx);
 }
 
@@ -2989,7 +3731,11 @@ pub(crate) unsafe fn walk_export_all_declaration<'a, Tr: Traverse<'a>>(
     node: *mut ExportAllDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ExportAllDeclaration");
     traverser.enter_export_all_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ExportAllDeclarationExported(
         ancestor::ExportAllDeclarationWithoutExported(node),
     ));
@@ -3013,6 +3759,8 @@ pub(crate) unsafe fn walk_export_all_declaration<'a, Tr: Traverse<'a>>(
         walk_with_clause(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ExportAllDeclaration");
     traverser.exit_export_all_declaration(&mut *node, ctx);
 }
 
@@ -3021,7 +3769,11 @@ pub(crate) unsafe fn walk_export_specifier<'a, Tr: Traverse<'a>>(
     node: *mut ExportSpecifier<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("ExportSpecifier");
     traverser.enter_export_specifier(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::ExportSpecifierLocal(ancestor::ExportSpecifierWithoutLocal(node)));
     walk_module_export_name(
         traverser,
@@ -3035,6 +3787,8 @@ pub(crate) unsafe fn walk_export_specifier<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "ExportSpecifier");
     traverser.exit_export_specifier(&mut *node, ctx);
 }
 
@@ -3043,7 +3797,18 @@ pub(crate) unsafe fn walk_export_default_declaration_kind<'a, Tr: Traverse<'a>>(
     node: *mut ExportDefaultDeclarationKind<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_export_default_declaration_kind(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_export_default_declaration_kind(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         ExportDefaultDeclarationKind::FunctionDeclaration(node) => {
             walk_function(traverser, (&mut **node) as *mut _, ctx)
@@ -3110,7 +3875,18 @@ pub(crate) unsafe fn walk_module_export_name<'a, Tr: Traverse<'a>>(
     node: *mut ModuleExportName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_module_export_name(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_module_export_name(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         ModuleExportName::Identifier(node) => walk_identifier_name(traverser, node as *mut _, ctx),
         ModuleExportName::StringLiteral(node) => {
@@ -3125,7 +3901,11 @@ pub(crate) unsafe fn walk_jsx_element<'a, Tr: Traverse<'a>>(
     node: *mut JSXElement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXElement");
     traverser.enter_jsx_element(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::JSXElementOpeningElement(ancestor::JSXElementWithoutOpeningElement(
         node,
     )));
@@ -3149,6 +3929,8 @@ pub(crate) unsafe fn walk_jsx_element<'a, Tr: Traverse<'a>>(
         walk_jsx_child(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "JSXElement");
     traverser.exit_jsx_element(&mut *node, ctx);
 }
 
@@ -3157,7 +3939,11 @@ pub(crate) unsafe fn walk_jsx_opening_element<'a, Tr: Traverse<'a>>(
     node: *mut JSXOpeningElement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXOpeningElement");
     traverser.enter_jsx_opening_element(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::JSXOpeningElementName(ancestor::JSXOpeningElementWithoutName(node)));
     walk_jsx_element_name(
         traverser,
@@ -3179,6 +3965,8 @@ pub(crate) unsafe fn walk_jsx_opening_element<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_instantiation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "JSXOpeningElement");
     traverser.exit_jsx_opening_element(&mut *node, ctx);
 }
 
@@ -3187,7 +3975,11 @@ pub(crate) unsafe fn walk_jsx_closing_element<'a, Tr: Traverse<'a>>(
     node: *mut JSXClosingElement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXClosingElement");
     traverser.enter_jsx_closing_element(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::JSXClosingElementName(ancestor::JSXClosingElementWithoutName(node)));
     walk_jsx_element_name(
         traverser,
@@ -3195,6 +3987,8 @@ pub(crate) unsafe fn walk_jsx_closing_element<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "JSXClosingElement");
     traverser.exit_jsx_closing_element(&mut *node, ctx);
 }
 
@@ -3203,7 +3997,11 @@ pub(crate) unsafe fn walk_jsx_fragment<'a, Tr: Traverse<'a>>(
     node: *mut JSXFragment<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXFragment");
     traverser.enter_jsx_fragment(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::JSXFragmentChildren(ancestor::JSXFragmentWithoutChildren(node)));
     for item in (*((node as *mut u8).add(ancestor::OFFSET_JSX_FRAGMENT_CHILDREN)
         as *mut Vec<JSXChild>))
@@ -3212,6 +4010,8 @@ pub(crate) unsafe fn walk_jsx_fragment<'a, Tr: Traverse<'a>>(
         walk_jsx_child(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "JSXFragment");
     traverser.exit_jsx_fragment(&mut *node, ctx);
 }
 
@@ -3220,7 +4020,18 @@ pub(crate) unsafe fn walk_jsx_element_name<'a, Tr: Traverse<'a>>(
     node: *mut JSXElementName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_jsx_element_name(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_jsx_element_name(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         JSXElementName::Identifier(node) => {
             walk_jsx_identifier(traverser, (&mut **node) as *mut _, ctx)
@@ -3240,7 +4051,11 @@ pub(crate) unsafe fn walk_jsx_namespaced_name<'a, Tr: Traverse<'a>>(
     node: *mut JSXNamespacedName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXNamespacedName");
     traverser.enter_jsx_namespaced_name(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::JSXNamespacedNameNamespace(
         ancestor::JSXNamespacedNameWithoutNamespace(node),
     ));
@@ -3256,6 +4071,8 @@ pub(crate) unsafe fn walk_jsx_namespaced_name<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "JSXNamespacedName");
     traverser.exit_jsx_namespaced_name(&mut *node, ctx);
 }
 
@@ -3264,7 +4081,11 @@ pub(crate) unsafe fn walk_jsx_member_expression<'a, Tr: Traverse<'a>>(
     node: *mut JSXMemberExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXMemberExpression");
     traverser.enter_jsx_member_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::JSXMemberExpressionObject(
         ancestor::JSXMemberExpressionWithoutObject(node),
     ));
@@ -3282,6 +4103,8 @@ pub(crate) unsafe fn walk_jsx_member_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "JSXMemberExpression");
     traverser.exit_jsx_member_expression(&mut *node, ctx);
 }
 
@@ -3290,7 +4113,18 @@ pub(crate) unsafe fn walk_jsx_member_expression_object<'a, Tr: Traverse<'a>>(
     node: *mut JSXMemberExpressionObject<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_jsx_member_expression_object(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_jsx_member_expression_object(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         JSXMemberExpressionObject::Identifier(node) => {
             walk_jsx_identifier(traverser, (&mut **node) as *mut _, ctx)
@@ -3307,7 +4141,11 @@ pub(crate) unsafe fn walk_jsx_expression_container<'a, Tr: Traverse<'a>>(
     node: *mut JSXExpressionContainer<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXExpressionContainer");
     traverser.enter_jsx_expression_container(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::JSXExpressionContainerExpression(
         ancestor::JSXExpressionContainerWithoutExpression(node),
     ));
@@ -3318,6 +4156,8 @@ pub(crate) unsafe fn walk_jsx_expression_container<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "JSXExpressionContainer");
     traverser.exit_jsx_expression_container(&mut *node, ctx);
 }
 
@@ -3326,7 +4166,18 @@ pub(crate) unsafe fn walk_jsx_expression<'a, Tr: Traverse<'a>>(
     node: *mut JSXExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_jsx_expression(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_jsx_expression(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         JSXExpression::EmptyExpression(node) => {
             walk_jsx_empty_expression(traverser, node as *mut _, ctx)
@@ -3384,6 +4235,8 @@ pub(crate) unsafe fn walk_jsx_empty_expression<'a, Tr: Traverse<'a>>(
     node: *mut JSXEmptyExpression,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXEmptyExpression");
     traverser.enter_jsx_empty_expression(&mut *node, ctx);
     traverser.exit_jsx_empty_expression(&mut *node, ctx);
 }
@@ -3393,7 +4246,18 @@ pub(crate) unsafe fn walk_jsx_attribute_item<'a, Tr: Traverse<'a>>(
     node: *mut JSXAttributeItem<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_jsx_attribute_item(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_jsx_attribute_item(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         JSXAttributeItem::Attribute(node) => {
             walk_jsx_attribute(traverser, (&mut **node) as *mut _, ctx)
@@ -3410,7 +4274,11 @@ pub(crate) unsafe fn walk_jsx_attribute<'a, Tr: Traverse<'a>>(
     node: *mut JSXAttribute<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXAttribute");
     traverser.enter_jsx_attribute(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::JSXAttributeName(ancestor::JSXAttributeWithoutName(node)));
     walk_jsx_attribute_name(
         traverser,
@@ -3424,6 +4292,8 @@ pub(crate) unsafe fn walk_jsx_attribute<'a, Tr: Traverse<'a>>(
         walk_jsx_attribute_value(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "JSXAttribute");
     traverser.exit_jsx_attribute(&mut *node, ctx);
 }
 
@@ -3432,7 +4302,11 @@ pub(crate) unsafe fn walk_jsx_spread_attribute<'a, Tr: Traverse<'a>>(
     node: *mut JSXSpreadAttribute<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXSpreadAttribute");
     traverser.enter_jsx_spread_attribute(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::JSXSpreadAttributeArgument(
         ancestor::JSXSpreadAttributeWithoutArgument(node),
     ));
@@ -3442,6 +4316,8 @@ pub(crate) unsafe fn walk_jsx_spread_attribute<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "JSXSpreadAttribute");
     traverser.exit_jsx_spread_attribute(&mut *node, ctx);
 }
 
@@ -3450,7 +4326,18 @@ pub(crate) unsafe fn walk_jsx_attribute_name<'a, Tr: Traverse<'a>>(
     node: *mut JSXAttributeName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_jsx_attribute_name(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_jsx_attribute_name(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         JSXAttributeName::Identifier(node) => {
             walk_jsx_identifier(traverser, (&mut **node) as *mut _, ctx)
@@ -3467,7 +4354,18 @@ pub(crate) unsafe fn walk_jsx_attribute_value<'a, Tr: Traverse<'a>>(
     node: *mut JSXAttributeValue<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_jsx_attribute_value(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_jsx_attribute_value(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         JSXAttributeValue::StringLiteral(node) => {
             walk_string_literal(traverser, (&mut **node) as *mut _, ctx)
@@ -3490,6 +4388,8 @@ pub(crate) unsafe fn walk_jsx_identifier<'a, Tr: Traverse<'a>>(
     node: *mut JSXIdentifier<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXIdentifier");
     traverser.enter_jsx_identifier(&mut *node, ctx);
     traverser.exit_jsx_identifier(&mut *node, ctx);
 }
@@ -3499,7 +4399,18 @@ pub(crate) unsafe fn walk_jsx_child<'a, Tr: Traverse<'a>>(
     node: *mut JSXChild<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_jsx_child(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_jsx_child(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         JSXChild::Text(node) => walk_jsx_text(traverser, (&mut **node) as *mut _, ctx),
         JSXChild::Element(node) => walk_jsx_element(traverser, (&mut **node) as *mut _, ctx),
@@ -3517,7 +4428,11 @@ pub(crate) unsafe fn walk_jsx_spread_child<'a, Tr: Traverse<'a>>(
     node: *mut JSXSpreadChild<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXSpreadChild");
     traverser.enter_jsx_spread_child(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::JSXSpreadChildExpression(ancestor::JSXSpreadChildWithoutExpression(
         node,
     )));
@@ -3527,6 +4442,8 @@ pub(crate) unsafe fn walk_jsx_spread_child<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "JSXSpreadChild");
     traverser.exit_jsx_spread_child(&mut *node, ctx);
 }
 
@@ -3535,6 +4452,8 @@ pub(crate) unsafe fn walk_jsx_text<'a, Tr: Traverse<'a>>(
     node: *mut JSXText<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSXText");
     traverser.enter_jsx_text(&mut *node, ctx);
     traverser.exit_jsx_text(&mut *node, ctx);
 }
@@ -3544,6 +4463,8 @@ pub(crate) unsafe fn walk_boolean_literal<'a, Tr: Traverse<'a>>(
     node: *mut BooleanLiteral,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("BooleanLiteral");
     traverser.enter_boolean_literal(&mut *node, ctx);
     traverser.exit_boolean_literal(&mut *node, ctx);
 }
@@ -3553,6 +4474,8 @@ pub(crate) unsafe fn walk_null_literal<'a, Tr: Traverse<'a>>(
     node: *mut NullLiteral,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("NullLiteral");
     traverser.enter_null_literal(&mut *node, ctx);
     traverser.exit_null_literal(&mut *node, ctx);
 }
@@ -3562,6 +4485,8 @@ pub(crate) unsafe fn walk_numeric_literal<'a, Tr: Traverse<'a>>(
     node: *mut NumericLiteral<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("NumericLiteral");
     traverser.enter_numeric_literal(&mut *node, ctx);
     traverser.exit_numeric_literal(&mut *node, ctx);
 }
@@ -3571,6 +4496,8 @@ pub(crate) unsafe fn walk_big_int_literal<'a, Tr: Traverse<'a>>(
     node: *mut BigIntLiteral<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("BigIntLiteral");
     traverser.enter_big_int_literal(&mut *node, ctx);
     traverser.exit_big_int_literal(&mut *node, ctx);
 }
@@ -3580,6 +4507,8 @@ pub(crate) unsafe fn walk_reg_exp_literal<'a, Tr: Traverse<'a>>(
     node: *mut RegExpLiteral<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("RegExpLiteral");
     traverser.enter_reg_exp_literal(&mut *node, ctx);
     traverser.exit_reg_exp_literal(&mut *node, ctx);
 }
@@ -3589,6 +4518,8 @@ pub(crate) unsafe fn walk_string_literal<'a, Tr: Traverse<'a>>(
     node: *mut StringLiteral<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("StringLiteral");
     traverser.enter_string_literal(&mut *node, ctx);
     traverser.exit_string_literal(&mut *node, ctx);
 }
@@ -3598,7 +4529,11 @@ pub(crate) unsafe fn walk_ts_this_parameter<'a, Tr: Traverse<'a>>(
     node: *mut TSThisParameter<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSThisParameter");
     traverser.enter_ts_this_parameter(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSThisParameterThis(ancestor::TSThisParameterWithoutThis(node)));
     walk_identifier_name(
         traverser,
@@ -3613,6 +4548,8 @@ pub(crate) unsafe fn walk_ts_this_parameter<'a, Tr: Traverse<'a>>(
         walk_ts_type_annotation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSThisParameter");
     traverser.exit_ts_this_parameter(&mut *node, ctx);
 }
 
@@ -3621,7 +4558,11 @@ pub(crate) unsafe fn walk_ts_enum_declaration<'a, Tr: Traverse<'a>>(
     node: *mut TSEnumDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSEnumDeclaration");
     traverser.enter_ts_enum_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSEnumDeclarationId(ancestor::TSEnumDeclarationWithoutId(node)));
     walk_binding_identifier(
         traverser,
@@ -3644,6 +4585,8 @@ pub(crate) unsafe fn walk_ts_enum_declaration<'a, Tr: Traverse<'a>>(
         walk_ts_enum_member(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSEnumDeclaration");
     traverser.exit_ts_enum_declaration(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -3655,7 +4598,11 @@ pub(crate) unsafe fn walk_ts_enum_member<'a, Tr: Traverse<'a>>(
     node: *mut TSEnumMember<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSEnumMember");
     traverser.enter_ts_enum_member(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSEnumMemberId(ancestor::TSEnumMemberWithoutId(node)));
     walk_ts_enum_member_name(
         traverser,
@@ -3669,6 +4616,8 @@ pub(crate) unsafe fn walk_ts_enum_member<'a, Tr: Traverse<'a>>(
         walk_expression(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSEnumMember");
     traverser.exit_ts_enum_member(&mut *node, ctx);
 }
 
@@ -3677,7 +4626,18 @@ pub(crate) unsafe fn walk_ts_enum_member_name<'a, Tr: Traverse<'a>>(
     node: *mut TSEnumMemberName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_ts_enum_member_name(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_ts_enum_member_name(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         TSEnumMemberName::StaticIdentifier(node) => {
             walk_identifier_name(traverser, (&mut **node) as *mut _, ctx)
@@ -3741,7 +4701,11 @@ pub(crate) unsafe fn walk_ts_type_annotation<'a, Tr: Traverse<'a>>(
     node: *mut TSTypeAnnotation<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTypeAnnotation");
     traverser.enter_ts_type_annotation(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTypeAnnotationTypeAnnotation(
         ancestor::TSTypeAnnotationWithoutTypeAnnotation(node),
     ));
@@ -3751,6 +4715,8 @@ pub(crate) unsafe fn walk_ts_type_annotation<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTypeAnnotation");
     traverser.exit_ts_type_annotation(&mut *node, ctx);
 }
 
@@ -3759,7 +4725,11 @@ pub(crate) unsafe fn walk_ts_literal_type<'a, Tr: Traverse<'a>>(
     node: *mut TSLiteralType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSLiteralType");
     traverser.enter_ts_literal_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSLiteralTypeLiteral(ancestor::TSLiteralTypeWithoutLiteral(node)));
     walk_ts_literal(
         traverser,
@@ -3767,6 +4737,8 @@ pub(crate) unsafe fn walk_ts_literal_type<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSLiteralType");
     traverser.exit_ts_literal_type(&mut *node, ctx);
 }
 
@@ -3775,7 +4747,18 @@ pub(crate) unsafe fn walk_ts_literal<'a, Tr: Traverse<'a>>(
     node: *mut TSLiteral<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_ts_literal(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_ts_literal(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         TSLiteral::BooleanLiteral(node) => {
             walk_boolean_literal(traverser, (&mut **node) as *mut _, ctx)
@@ -3808,7 +4791,18 @@ pub(crate) unsafe fn walk_ts_type<'a, Tr: Traverse<'a>>(
     node: *mut TSType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_ts_type(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_ts_type(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         TSType::TSAnyKeyword(node) => walk_ts_any_keyword(traverser, (&mut **node) as *mut _, ctx),
         TSType::TSBigIntKeyword(node) => {
@@ -3906,7 +4900,11 @@ pub(crate) unsafe fn walk_ts_conditional_type<'a, Tr: Traverse<'a>>(
     node: *mut TSConditionalType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSConditionalType");
     traverser.enter_ts_conditional_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSConditionalTypeCheckType(
         ancestor::TSConditionalTypeWithoutCheckType(node),
     ));
@@ -3934,6 +4932,8 @@ pub(crate) unsafe fn walk_ts_conditional_type<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSConditionalType");
     traverser.exit_ts_conditional_type(&mut *node, ctx);
 }
 
@@ -3942,7 +4942,11 @@ pub(crate) unsafe fn walk_ts_union_type<'a, Tr: Traverse<'a>>(
     node: *mut TSUnionType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSUnionType");
     traverser.enter_ts_union_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSUnionTypeTypes(ancestor::TSUnionTypeWithoutTypes(node)));
     for item in (*((node as *mut u8).add(ancestor::OFFSET_TS_UNION_TYPE_TYPES) as *mut Vec<TSType>))
         .iter_mut()
@@ -3950,6 +4954,8 @@ pub(crate) unsafe fn walk_ts_union_type<'a, Tr: Traverse<'a>>(
         walk_ts_type(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSUnionType");
     traverser.exit_ts_union_type(&mut *node, ctx);
 }
 
@@ -3958,7 +4964,11 @@ pub(crate) unsafe fn walk_ts_intersection_type<'a, Tr: Traverse<'a>>(
     node: *mut TSIntersectionType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSIntersectionType");
     traverser.enter_ts_intersection_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSIntersectionTypeTypes(ancestor::TSIntersectionTypeWithoutTypes(
         node,
     )));
@@ -3969,6 +4979,8 @@ pub(crate) unsafe fn walk_ts_intersection_type<'a, Tr: Traverse<'a>>(
         walk_ts_type(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSIntersectionType");
     traverser.exit_ts_intersection_type(&mut *node, ctx);
 }
 
@@ -3977,7 +4989,11 @@ pub(crate) unsafe fn walk_ts_type_operator<'a, Tr: Traverse<'a>>(
     node: *mut TSTypeOperator<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTypeOperator");
     traverser.enter_ts_type_operator(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTypeOperatorTypeAnnotation(
         ancestor::TSTypeOperatorWithoutTypeAnnotation(node),
     ));
@@ -3987,6 +5003,8 @@ pub(crate) unsafe fn walk_ts_type_operator<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTypeOperator");
     traverser.exit_ts_type_operator(&mut *node, ctx);
 }
 
@@ -3995,7 +5013,11 @@ pub(crate) unsafe fn walk_ts_array_type<'a, Tr: Traverse<'a>>(
     node: *mut TSArrayType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSArrayType");
     traverser.enter_ts_array_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSArrayTypeElementType(ancestor::TSArrayTypeWithoutElementType(node)));
     walk_ts_type(
         traverser,
@@ -4003,6 +5025,8 @@ pub(crate) unsafe fn walk_ts_array_type<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSArrayType");
     traverser.exit_ts_array_type(&mut *node, ctx);
 }
 
@@ -4011,7 +5035,11 @@ pub(crate) unsafe fn walk_ts_indexed_access_type<'a, Tr: Traverse<'a>>(
     node: *mut TSIndexedAccessType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSIndexedAccessType");
     traverser.enter_ts_indexed_access_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSIndexedAccessTypeObjectType(
         ancestor::TSIndexedAccessTypeWithoutObjectType(node),
     ));
@@ -4027,6 +5055,8 @@ pub(crate) unsafe fn walk_ts_indexed_access_type<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSIndexedAccessType");
     traverser.exit_ts_indexed_access_type(&mut *node, ctx);
 }
 
@@ -4035,7 +5065,11 @@ pub(crate) unsafe fn walk_ts_tuple_type<'a, Tr: Traverse<'a>>(
     node: *mut TSTupleType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTupleType");
     traverser.enter_ts_tuple_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTupleTypeElementTypes(ancestor::TSTupleTypeWithoutElementTypes(
         node,
     )));
@@ -4046,6 +5080,8 @@ pub(crate) unsafe fn walk_ts_tuple_type<'a, Tr: Traverse<'a>>(
         walk_ts_tuple_element(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTupleType");
     traverser.exit_ts_tuple_type(&mut *node, ctx);
 }
 
@@ -4054,7 +5090,11 @@ pub(crate) unsafe fn walk_ts_named_tuple_member<'a, Tr: Traverse<'a>>(
     node: *mut TSNamedTupleMember<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSNamedTupleMember");
     traverser.enter_ts_named_tuple_member(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSNamedTupleMemberElementType(
         ancestor::TSNamedTupleMemberWithoutElementType(node),
     ));
@@ -4070,6 +5110,8 @@ pub(crate) unsafe fn walk_ts_named_tuple_member<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSNamedTupleMember");
     traverser.exit_ts_named_tuple_member(&mut *node, ctx);
 }
 
@@ -4078,7 +5120,11 @@ pub(crate) unsafe fn walk_ts_optional_type<'a, Tr: Traverse<'a>>(
     node: *mut TSOptionalType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSOptionalType");
     traverser.enter_ts_optional_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSOptionalTypeTypeAnnotation(
         ancestor::TSOptionalTypeWithoutTypeAnnotation(node),
     ));
@@ -4088,6 +5134,8 @@ pub(crate) unsafe fn walk_ts_optional_type<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSOptionalType");
     traverser.exit_ts_optional_type(&mut *node, ctx);
 }
 
@@ -4096,7 +5144,11 @@ pub(crate) unsafe fn walk_ts_rest_type<'a, Tr: Traverse<'a>>(
     node: *mut TSRestType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSRestType");
     traverser.enter_ts_rest_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSRestTypeTypeAnnotation(ancestor::TSRestTypeWithoutTypeAnnotation(
         node,
     )));
@@ -4106,6 +5158,8 @@ pub(crate) unsafe fn walk_ts_rest_type<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSRestType");
     traverser.exit_ts_rest_type(&mut *node, ctx);
 }
 
@@ -4114,7 +5168,18 @@ pub(crate) unsafe fn walk_ts_tuple_element<'a, Tr: Traverse<'a>>(
     node: *mut TSTupleElement<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_ts_tuple_element(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_ts_tuple_element(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         TSTupleElement::TSOptionalType(node) => {
             walk_ts_optional_type(traverser, (&mut **node) as *mut _, ctx)
@@ -4166,6 +5231,8 @@ pub(crate) unsafe fn walk_ts_any_keyword<'a, Tr: Traverse<'a>>(
     node: *mut TSAnyKeyword,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSAnyKeyword");
     traverser.enter_ts_any_keyword(&mut *node, ctx);
     traverser.exit_ts_any_keyword(&mut *node, ctx);
 }
@@ -4175,6 +5242,8 @@ pub(crate) unsafe fn walk_ts_string_keyword<'a, Tr: Traverse<'a>>(
     node: *mut TSStringKeyword,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSStringKeyword");
     traverser.enter_ts_string_keyword(&mut *node, ctx);
     traverser.exit_ts_string_keyword(&mut *node, ctx);
 }
@@ -4184,6 +5253,8 @@ pub(crate) unsafe fn walk_ts_boolean_keyword<'a, Tr: Traverse<'a>>(
     node: *mut TSBooleanKeyword,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSBooleanKeyword");
     traverser.enter_ts_boolean_keyword(&mut *node, ctx);
     traverser.exit_ts_boolean_keyword(&mut *node, ctx);
 }
@@ -4193,6 +5264,8 @@ pub(crate) unsafe fn walk_ts_number_keyword<'a, Tr: Traverse<'a>>(
     node: *mut TSNumberKeyword,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSNumberKeyword");
     traverser.enter_ts_number_keyword(&mut *node, ctx);
     traverser.exit_ts_number_keyword(&mut *node, ctx);
 }
@@ -4202,6 +5275,8 @@ pub(crate) unsafe fn walk_ts_never_keyword<'a, Tr: Traverse<'a>>(
     node: *mut TSNeverKeyword,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSNeverKeyword");
     traverser.enter_ts_never_keyword(&mut *node, ctx);
     traverser.exit_ts_never_keyword(&mut *node, ctx);
 }
@@ -4211,6 +5286,8 @@ pub(crate) unsafe fn walk_ts_unknown_keyword<'a, Tr: Traverse<'a>>(
     node: *mut TSUnknownKeyword,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSUnknownKeyword");
     traverser.enter_ts_unknown_keyword(&mut *node, ctx);
     traverser.exit_ts_unknown_keyword(&mut *node, ctx);
 }
@@ -4220,6 +5297,8 @@ pub(crate) unsafe fn walk_ts_null_keyword<'a, Tr: Traverse<'a>>(
     node: *mut TSNullKeyword,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSNullKeyword");
     traverser.enter_ts_null_keyword(&mut *node, ctx);
     traverser.exit_ts_null_keyword(&mut *node, ctx);
 }
@@ -4229,6 +5308,8 @@ pub(crate) unsafe fn walk_ts_undefined_keyword<'a, Tr: Traverse<'a>>(
     node: *mut TSUndefinedKeyword,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSUndefinedKeyword");
     traverser.enter_ts_undefined_keyword(&mut *node, ctx);
     traverser.exit_ts_undefined_keyword(&mut *node, ctx);
 }
@@ -4238,6 +5319,8 @@ pub(crate) unsafe fn walk_ts_void_keyword<'a, Tr: Traverse<'a>>(
     node: *mut TSVoidKeyword,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSVoidKeyword");
     traverser.enter_ts_void_keyword(&mut *node, ctx);
     traverser.exit_ts_void_keyword(&mut *node, ctx);
 }
@@ -4247,6 +5330,8 @@ pub(crate) unsafe fn walk_ts_symbol_keyword<'a, Tr: Traverse<'a>>(
     node: *mut TSSymbolKeyword,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSSymbolKeyword");
     traverser.enter_ts_symbol_keyword(&mut *node, ctx);
     traverser.exit_ts_symbol_keyword(&mut *node, ctx);
 }
@@ -4256,6 +5341,8 @@ pub(crate) unsafe fn walk_ts_this_type<'a, Tr: Traverse<'a>>(
     node: *mut TSThisType,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSThisType");
     traverser.enter_ts_this_type(&mut *node, ctx);
     traverser.exit_ts_this_type(&mut *node, ctx);
 }
@@ -4265,6 +5352,8 @@ pub(crate) unsafe fn walk_ts_object_keyword<'a, Tr: Traverse<'a>>(
     node: *mut TSObjectKeyword,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSObjectKeyword");
     traverser.enter_ts_object_keyword(&mut *node, ctx);
     traverser.exit_ts_object_keyword(&mut *node, ctx);
 }
@@ -4274,6 +5363,8 @@ pub(crate) unsafe fn walk_ts_big_int_keyword<'a, Tr: Traverse<'a>>(
     node: *mut TSBigIntKeyword,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSBigIntKeyword");
     traverser.enter_ts_big_int_keyword(&mut *node, ctx);
     traverser.exit_ts_big_int_keyword(&mut *node, ctx);
 }
@@ -4283,7 +5374,11 @@ pub(crate) unsafe fn walk_ts_type_reference<'a, Tr: Traverse<'a>>(
     node: *mut TSTypeReference<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTypeReference");
     traverser.enter_ts_type_reference(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTypeReferenceTypeName(ancestor::TSTypeReferenceWithoutTypeName(
         node,
     )));
@@ -4300,6 +5395,8 @@ pub(crate) unsafe fn walk_ts_type_reference<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_instantiation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTypeReference");
     traverser.exit_ts_type_reference(&mut *node, ctx);
 }
 
@@ -4308,7 +5405,18 @@ pub(crate) unsafe fn walk_ts_type_name<'a, Tr: Traverse<'a>>(
     node: *mut TSTypeName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_ts_type_name(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_ts_type_name(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         TSTypeName::IdentifierReference(node) => {
             walk_identifier_reference(traverser, (&mut **node) as *mut _, ctx)
@@ -4325,7 +5433,11 @@ pub(crate) unsafe fn walk_ts_qualified_name<'a, Tr: Traverse<'a>>(
     node: *mut TSQualifiedName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSQualifiedName");
     traverser.enter_ts_qualified_name(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSQualifiedNameLeft(ancestor::TSQualifiedNameWithoutLeft(node)));
     walk_ts_type_name(
         traverser,
@@ -4339,6 +5451,8 @@ pub(crate) unsafe fn walk_ts_qualified_name<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSQualifiedName");
     traverser.exit_ts_qualified_name(&mut *node, ctx);
 }
 
@@ -4347,7 +5461,11 @@ pub(crate) unsafe fn walk_ts_type_parameter_instantiation<'a, Tr: Traverse<'a>>(
     node: *mut TSTypeParameterInstantiation<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTypeParameterInstantiation");
     traverser.enter_ts_type_parameter_instantiation(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTypeParameterInstantiationParams(
         ancestor::TSTypeParameterInstantiationWithoutParams(node),
     ));
@@ -4358,6 +5476,8 @@ pub(crate) unsafe fn walk_ts_type_parameter_instantiation<'a, Tr: Traverse<'a>>(
         walk_ts_type(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTypeParameterInstantiation");
     traverser.exit_ts_type_parameter_instantiation(&mut *node, ctx);
 }
 
@@ -4374,7 +5494,11 @@ pub(crate) unsafe fn walk_ts_type_parameter<'a, Tr: Traverse<'a>>(
         previous_scope_id = Some(ctx.current_scope_id());
         ctx.set_current_scope_id(scope_id);
     }
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTypeParameter");
     traverser.enter_ts_type_parameter(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTypeParameterName(ancestor::TSTypeParameterWithoutName(node)));
     walk_binding_identifier(
         traverser,
@@ -4394,6 +5518,8 @@ pub(crate) unsafe fn walk_ts_type_parameter<'a, Tr: Traverse<'a>>(
         walk_ts_type(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTypeParameter");
     traverser.exit_ts_type_parameter(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -4405,7 +5531,11 @@ pub(crate) unsafe fn walk_ts_type_parameter_declaration<'a, Tr: Traverse<'a>>(
     node: *mut TSTypeParameterDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTypeParameterDeclaration");
     traverser.enter_ts_type_parameter_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTypeParameterDeclarationParams(
         ancestor::TSTypeParameterDeclarationWithoutParams(node),
     ));
@@ -4416,6 +5546,8 @@ pub(crate) unsafe fn walk_ts_type_parameter_declaration<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTypeParameterDeclaration");
     traverser.exit_ts_type_parameter_declaration(&mut *node, ctx);
 }
 
@@ -4424,7 +5556,11 @@ pub(crate) unsafe fn walk_ts_type_alias_declaration<'a, Tr: Traverse<'a>>(
     node: *mut TSTypeAliasDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTypeAliasDeclaration");
     traverser.enter_ts_type_alias_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTypeAliasDeclarationId(ancestor::TSTypeAliasDeclarationWithoutId(
         node,
     )));
@@ -4449,6 +5585,8 @@ pub(crate) unsafe fn walk_ts_type_alias_declaration<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_declaration(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTypeAliasDeclaration");
     traverser.exit_ts_type_alias_declaration(&mut *node, ctx);
 }
 
@@ -4457,7 +5595,11 @@ pub(crate) unsafe fn walk_ts_class_implements<'a, Tr: Traverse<'a>>(
     node: *mut TSClassImplements<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSClassImplements");
     traverser.enter_ts_class_implements(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSClassImplementsExpression(
         ancestor::TSClassImplementsWithoutExpression(node),
     ));
@@ -4474,6 +5616,8 @@ pub(crate) unsafe fn walk_ts_class_implements<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_instantiation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSClassImplements");
     traverser.exit_ts_class_implements(&mut *node, ctx);
 }
 
@@ -4482,7 +5626,11 @@ pub(crate) unsafe fn walk_ts_interface_declaration<'a, Tr: Traverse<'a>>(
     node: *mut TSInterfaceDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSInterfaceDeclaration");
     traverser.enter_ts_interface_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSInterfaceDeclarationId(ancestor::TSInterfaceDeclarationWithoutId(
         node,
     )));
@@ -4516,6 +5664,8 @@ pub(crate) unsafe fn walk_ts_interface_declaration<'a, Tr: Traverse<'a>>(
         }
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSInterfaceDeclaration");
     traverser.exit_ts_interface_declaration(&mut *node, ctx);
 }
 
@@ -4524,7 +5674,11 @@ pub(crate) unsafe fn walk_ts_interface_body<'a, Tr: Traverse<'a>>(
     node: *mut TSInterfaceBody<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSInterfaceBody");
     traverser.enter_ts_interface_body(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSInterfaceBodyBody(ancestor::TSInterfaceBodyWithoutBody(node)));
     for item in (*((node as *mut u8).add(ancestor::OFFSET_TS_INTERFACE_BODY_BODY)
         as *mut Vec<TSSignature>))
@@ -4533,6 +5687,8 @@ pub(crate) unsafe fn walk_ts_interface_body<'a, Tr: Traverse<'a>>(
         walk_ts_signature(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSInterfaceBody");
     traverser.exit_ts_interface_body(&mut *node, ctx);
 }
 
@@ -4541,7 +5697,11 @@ pub(crate) unsafe fn walk_ts_property_signature<'a, Tr: Traverse<'a>>(
     node: *mut TSPropertySignature<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSPropertySignature");
     traverser.enter_ts_property_signature(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSPropertySignatureKey(ancestor::TSPropertySignatureWithoutKey(node)));
     walk_property_key(
         traverser,
@@ -4556,6 +5716,8 @@ pub(crate) unsafe fn walk_ts_property_signature<'a, Tr: Traverse<'a>>(
         walk_ts_type_annotation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSPropertySignature");
     traverser.exit_ts_property_signature(&mut *node, ctx);
 }
 
@@ -4564,7 +5726,18 @@ pub(crate) unsafe fn walk_ts_signature<'a, Tr: Traverse<'a>>(
     node: *mut TSSignature<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_ts_signature(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_ts_signature(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         TSSignature::TSIndexSignature(node) => {
             walk_ts_index_signature(traverser, (&mut **node) as *mut _, ctx)
@@ -4590,7 +5763,11 @@ pub(crate) unsafe fn walk_ts_index_signature<'a, Tr: Traverse<'a>>(
     node: *mut TSIndexSignature<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSIndexSignature");
     traverser.enter_ts_index_signature(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSIndexSignatureParameters(
         ancestor::TSIndexSignatureWithoutParameters(node),
     ));
@@ -4608,6 +5785,8 @@ pub(crate) unsafe fn walk_ts_index_signature<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSIndexSignature");
     traverser.exit_ts_index_signature(&mut *node, ctx);
 }
 
@@ -4616,7 +5795,11 @@ pub(crate) unsafe fn walk_ts_call_signature_declaration<'a, Tr: Traverse<'a>>(
     node: *mut TSCallSignatureDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSCallSignatureDeclaration");
     traverser.enter_ts_call_signature_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSCallSignatureDeclarationThisParam(
         ancestor::TSCallSignatureDeclarationWithoutThisParam(node),
     ));
@@ -4648,6 +5831,8 @@ pub(crate) unsafe fn walk_ts_call_signature_declaration<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_declaration(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSCallSignatureDeclaration");
     traverser.exit_ts_call_signature_declaration(&mut *node, ctx);
 }
 
@@ -4656,7 +5841,11 @@ pub(crate) unsafe fn walk_ts_method_signature<'a, Tr: Traverse<'a>>(
     node: *mut TSMethodSignature<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSMethodSignature");
     traverser.enter_ts_method_signature(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSMethodSignatureKey(ancestor::TSMethodSignatureWithoutKey(node)));
     walk_property_key(
         traverser,
@@ -4692,6 +5881,8 @@ pub(crate) unsafe fn walk_ts_method_signature<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_declaration(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSMethodSignature");
     traverser.exit_ts_method_signature(&mut *node, ctx);
 }
 
@@ -4700,7 +5891,11 @@ pub(crate) unsafe fn walk_ts_construct_signature_declaration<'a, Tr: Traverse<'a
     node: *mut TSConstructSignatureDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSConstructSignatureDeclaration");
     traverser.enter_ts_construct_signature_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSConstructSignatureDeclarationParams(
         ancestor::TSConstructSignatureDeclarationWithoutParams(node),
     ));
@@ -4725,6 +5920,8 @@ pub(crate) unsafe fn walk_ts_construct_signature_declaration<'a, Tr: Traverse<'a
         walk_ts_type_parameter_declaration(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSConstructSignatureDeclaration");
     traverser.exit_ts_construct_signature_declaration(&mut *node, ctx);
 }
 
@@ -4733,7 +5930,11 @@ pub(crate) unsafe fn walk_ts_index_signature_name<'a, Tr: Traverse<'a>>(
     node: *mut TSIndexSignatureName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSIndexSignatureName");
     traverser.enter_ts_index_signature_name(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSIndexSignatureNameTypeAnnotation(
         ancestor::TSIndexSignatureNameWithoutTypeAnnotation(node),
     ));
@@ -4744,6 +5945,8 @@ pub(crate) unsafe fn walk_ts_index_signature_name<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSIndexSignatureName");
     traverser.exit_ts_index_signature_name(&mut *node, ctx);
 }
 
@@ -4752,7 +5955,11 @@ pub(crate) unsafe fn walk_ts_interface_heritage<'a, Tr: Traverse<'a>>(
     node: *mut TSInterfaceHeritage<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSInterfaceHeritage");
     traverser.enter_ts_interface_heritage(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSInterfaceHeritageExpression(
         ancestor::TSInterfaceHeritageWithoutExpression(node),
     ));
@@ -4769,6 +5976,8 @@ pub(crate) unsafe fn walk_ts_interface_heritage<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_instantiation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSInterfaceHeritage");
     traverser.exit_ts_interface_heritage(&mut *node, ctx);
 }
 
@@ -4777,7 +5986,11 @@ pub(crate) unsafe fn walk_ts_type_predicate<'a, Tr: Traverse<'a>>(
     node: *mut TSTypePredicate<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTypePredicate");
     traverser.enter_ts_type_predicate(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTypePredicateParameterName(
         ancestor::TSTypePredicateWithoutParameterName(node),
     ));
@@ -4795,6 +6008,8 @@ pub(crate) unsafe fn walk_ts_type_predicate<'a, Tr: Traverse<'a>>(
         walk_ts_type_annotation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTypePredicate");
     traverser.exit_ts_type_predicate(&mut *node, ctx);
 }
 
@@ -4803,7 +6018,18 @@ pub(crate) unsafe fn walk_ts_type_predicate_name<'a, Tr: Traverse<'a>>(
     node: *mut TSTypePredicateName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_ts_type_predicate_name(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_ts_type_predicate_name(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         TSTypePredicateName::Identifier(node) => {
             walk_identifier_name(traverser, (&mut **node) as *mut _, ctx)
@@ -4818,7 +6044,11 @@ pub(crate) unsafe fn walk_ts_module_declaration<'a, Tr: Traverse<'a>>(
     node: *mut TSModuleDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSModuleDeclaration");
     traverser.enter_ts_module_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSModuleDeclarationId(ancestor::TSModuleDeclarationWithoutId(node)));
     walk_ts_module_declaration_name(
         traverser,
@@ -4833,6 +6063,8 @@ pub(crate) unsafe fn walk_ts_module_declaration<'a, Tr: Traverse<'a>>(
         walk_ts_module_declaration_body(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSModuleDeclaration");
     traverser.exit_ts_module_declaration(&mut *node, ctx);
 }
 
@@ -4841,7 +6073,18 @@ pub(crate) unsafe fn walk_ts_module_declaration_name<'a, Tr: Traverse<'a>>(
     node: *mut TSModuleDeclarationName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_ts_module_declaration_name(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_ts_module_declaration_name(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         TSModuleDeclarationName::Identifier(node) => {
             walk_identifier_name(traverser, node as *mut _, ctx)
@@ -4858,7 +6101,18 @@ pub(crate) unsafe fn walk_ts_module_declaration_body<'a, Tr: Traverse<'a>>(
     node: *mut TSModuleDeclarationBody<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_ts_module_declaration_body(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_ts_module_declaration_body(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         TSModuleDeclarationBody::TSModuleDeclaration(node) => {
             walk_ts_module_declaration(traverser, (&mut **node) as *mut _, ctx)
@@ -4883,7 +6137,11 @@ pub(crate) unsafe fn walk_ts_module_block<'a, Tr: Traverse<'a>>(
         previous_scope_id = Some(ctx.current_scope_id());
         ctx.set_current_scope_id(scope_id);
     }
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSModuleBlock");
     traverser.enter_ts_module_block(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSModuleBlockBody(ancestor::TSModuleBlockWithoutBody(node)));
     walk_statements(
         traverser,
@@ -4891,6 +6149,8 @@ pub(crate) unsafe fn walk_ts_module_block<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSModuleBlock");
     traverser.exit_ts_module_block(&mut *node, ctx);
     if let Some(previous_scope_id) = previous_scope_id {
         ctx.set_current_scope_id(previous_scope_id);
@@ -4902,7 +6162,11 @@ pub(crate) unsafe fn walk_ts_type_literal<'a, Tr: Traverse<'a>>(
     node: *mut TSTypeLiteral<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTypeLiteral");
     traverser.enter_ts_type_literal(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTypeLiteralMembers(ancestor::TSTypeLiteralWithoutMembers(node)));
     for item in (*((node as *mut u8).add(ancestor::OFFSET_TS_TYPE_LITERAL_MEMBERS)
         as *mut Vec<TSSignature>))
@@ -4911,6 +6175,8 @@ pub(crate) unsafe fn walk_ts_type_literal<'a, Tr: Traverse<'a>>(
         walk_ts_signature(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTypeLiteral");
     traverser.exit_ts_type_literal(&mut *node, ctx);
 }
 
@@ -4919,7 +6185,11 @@ pub(crate) unsafe fn walk_ts_infer_type<'a, Tr: Traverse<'a>>(
     node: *mut TSInferType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSInferType");
     traverser.enter_ts_infer_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSInferTypeTypeParameter(ancestor::TSInferTypeWithoutTypeParameter(
         node,
     )));
@@ -4930,6 +6200,8 @@ pub(crate) unsafe fn walk_ts_infer_type<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSInferType");
     traverser.exit_ts_infer_type(&mut *node, ctx);
 }
 
@@ -4938,7 +6210,11 @@ pub(crate) unsafe fn walk_ts_type_query<'a, Tr: Traverse<'a>>(
     node: *mut TSTypeQuery<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTypeQuery");
     traverser.enter_ts_type_query(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTypeQueryExprName(ancestor::TSTypeQueryWithoutExprName(node)));
     walk_ts_type_query_expr_name(
         traverser,
@@ -4953,6 +6229,8 @@ pub(crate) unsafe fn walk_ts_type_query<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_instantiation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTypeQuery");
     traverser.exit_ts_type_query(&mut *node, ctx);
 }
 
@@ -4961,7 +6239,18 @@ pub(crate) unsafe fn walk_ts_type_query_expr_name<'a, Tr: Traverse<'a>>(
     node: *mut TSTypeQueryExprName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_ts_type_query_expr_name(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_ts_type_query_expr_name(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         TSTypeQueryExprName::TSImportType(node) => {
             walk_ts_import_type(traverser, (&mut **node) as *mut _, ctx)
@@ -4978,7 +6267,11 @@ pub(crate) unsafe fn walk_ts_import_type<'a, Tr: Traverse<'a>>(
     node: *mut TSImportType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSImportType");
     traverser.enter_ts_import_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSImportTypeArgument(ancestor::TSImportTypeWithoutArgument(node)));
     walk_ts_type(
         traverser,
@@ -5005,6 +6298,8 @@ pub(crate) unsafe fn walk_ts_import_type<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_instantiation(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSImportType");
     traverser.exit_ts_import_type(&mut *node, ctx);
 }
 
@@ -5013,7 +6308,11 @@ pub(crate) unsafe fn walk_ts_import_attributes<'a, Tr: Traverse<'a>>(
     node: *mut TSImportAttributes<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSImportAttributes");
     traverser.enter_ts_import_attributes(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSImportAttributesElements(
         ancestor::TSImportAttributesWithoutElements(node),
     ));
@@ -5024,6 +6323,8 @@ pub(crate) unsafe fn walk_ts_import_attributes<'a, Tr: Traverse<'a>>(
         walk_ts_import_attribute(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSImportAttributes");
     traverser.exit_ts_import_attributes(&mut *node, ctx);
 }
 
@@ -5032,7 +6333,11 @@ pub(crate) unsafe fn walk_ts_import_attribute<'a, Tr: Traverse<'a>>(
     node: *mut TSImportAttribute<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSImportAttribute");
     traverser.enter_ts_import_attribute(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSImportAttributeName(ancestor::TSImportAttributeWithoutName(node)));
     walk_ts_import_attribute_name(
         traverser,
@@ -5047,6 +6352,8 @@ pub(crate) unsafe fn walk_ts_import_attribute<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSImportAttribute");
     traverser.exit_ts_import_attribute(&mut *node, ctx);
 }
 
@@ -5055,7 +6362,18 @@ pub(crate) unsafe fn walk_ts_import_attribute_name<'a, Tr: Traverse<'a>>(
     node: *mut TSImportAttributeName<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_ts_import_attribute_name(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_ts_import_attribute_name(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         TSImportAttributeName::Identifier(node) => {
             walk_identifier_name(traverser, node as *mut _, ctx)
@@ -5072,7 +6390,11 @@ pub(crate) unsafe fn walk_ts_function_type<'a, Tr: Traverse<'a>>(
     node: *mut TSFunctionType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSFunctionType");
     traverser.enter_ts_function_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSFunctionTypeThisParam(ancestor::TSFunctionTypeWithoutThisParam(
         node,
     )));
@@ -5103,6 +6425,8 @@ pub(crate) unsafe fn walk_ts_function_type<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_declaration(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSFunctionType");
     traverser.exit_ts_function_type(&mut *node, ctx);
 }
 
@@ -5111,7 +6435,11 @@ pub(crate) unsafe fn walk_ts_constructor_type<'a, Tr: Traverse<'a>>(
     node: *mut TSConstructorType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSConstructorType");
     traverser.enter_ts_constructor_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSConstructorTypeParams(ancestor::TSConstructorTypeWithoutParams(
         node,
     )));
@@ -5136,6 +6464,8 @@ pub(crate) unsafe fn walk_ts_constructor_type<'a, Tr: Traverse<'a>>(
         walk_ts_type_parameter_declaration(traverser, (&mut **field) as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSConstructorType");
     traverser.exit_ts_constructor_type(&mut *node, ctx);
 }
 
@@ -5144,7 +6474,11 @@ pub(crate) unsafe fn walk_ts_mapped_type<'a, Tr: Traverse<'a>>(
     node: *mut TSMappedType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSMappedType");
     traverser.enter_ts_mapped_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSMappedTypeTypeParameter(
         ancestor::TSMappedTypeWithoutTypeParameter(node),
     ));
@@ -5168,6 +6502,8 @@ pub(crate) unsafe fn walk_ts_mapped_type<'a, Tr: Traverse<'a>>(
         walk_ts_type(traverser, field as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSMappedType");
     traverser.exit_ts_mapped_type(&mut *node, ctx);
 }
 
@@ -5176,7 +6512,11 @@ pub(crate) unsafe fn walk_ts_template_literal_type<'a, Tr: Traverse<'a>>(
     node: *mut TSTemplateLiteralType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTemplateLiteralType");
     traverser.enter_ts_template_literal_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTemplateLiteralTypeQuasis(
         ancestor::TSTemplateLiteralTypeWithoutQuasis(node),
     ));
@@ -5194,6 +6534,8 @@ pub(crate) unsafe fn walk_ts_template_literal_type<'a, Tr: Traverse<'a>>(
         walk_ts_type(traverser, item as *mut _, ctx);
     }
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTemplateLiteralType");
     traverser.exit_ts_template_literal_type(&mut *node, ctx);
 }
 
@@ -5202,7 +6544,11 @@ pub(crate) unsafe fn walk_ts_as_expression<'a, Tr: Traverse<'a>>(
     node: *mut TSAsExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSAsExpression");
     traverser.enter_ts_as_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSAsExpressionExpression(ancestor::TSAsExpressionWithoutExpression(
         node,
     )));
@@ -5218,6 +6564,8 @@ pub(crate) unsafe fn walk_ts_as_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSAsExpression");
     traverser.exit_ts_as_expression(&mut *node, ctx);
 }
 
@@ -5226,7 +6574,11 @@ pub(crate) unsafe fn walk_ts_satisfies_expression<'a, Tr: Traverse<'a>>(
     node: *mut TSSatisfiesExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSSatisfiesExpression");
     traverser.enter_ts_satisfies_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSSatisfiesExpressionExpression(
         ancestor::TSSatisfiesExpressionWithoutExpression(node),
     ));
@@ -5244,6 +6596,8 @@ pub(crate) unsafe fn walk_ts_satisfies_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSSatisfiesExpression");
     traverser.exit_ts_satisfies_expression(&mut *node, ctx);
 }
 
@@ -5252,7 +6606,11 @@ pub(crate) unsafe fn walk_ts_type_assertion<'a, Tr: Traverse<'a>>(
     node: *mut TSTypeAssertion<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSTypeAssertion");
     traverser.enter_ts_type_assertion(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSTypeAssertionExpression(
         ancestor::TSTypeAssertionWithoutExpression(node),
     ));
@@ -5268,6 +6626,8 @@ pub(crate) unsafe fn walk_ts_type_assertion<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSTypeAssertion");
     traverser.exit_ts_type_assertion(&mut *node, ctx);
 }
 
@@ -5276,7 +6636,11 @@ pub(crate) unsafe fn walk_ts_import_equals_declaration<'a, Tr: Traverse<'a>>(
     node: *mut TSImportEqualsDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSImportEqualsDeclaration");
     traverser.enter_ts_import_equals_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSImportEqualsDeclarationId(
         ancestor::TSImportEqualsDeclarationWithoutId(node),
     ));
@@ -5294,6 +6658,8 @@ pub(crate) unsafe fn walk_ts_import_equals_declaration<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSImportEqualsDeclaration");
     traverser.exit_ts_import_equals_declaration(&mut *node, ctx);
 }
 
@@ -5302,7 +6668,18 @@ pub(crate) unsafe fn walk_ts_module_reference<'a, Tr: Traverse<'a>>(
     node: *mut TSModuleReference<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "revisit")]
+    ctx.clear_revisit();
     traverser.enter_ts_module_reference(&mut *node, ctx);
+    // Visit the node again if `enter_*` replaced it and asked for it to be revisited
+    #[cfg(feature = "revisit")]
+    {
+        let mut revisits = 0;
+        while ctx.take_revisit(revisits) {
+            revisits += 1;
+            traverser.enter_ts_module_reference(&mut *node, ctx);
+        }
+    }
     match &mut *node {
         TSModuleReference::ExternalModuleReference(node) => {
             walk_ts_external_module_reference(traverser, (&mut **node) as *mut _, ctx)
@@ -5319,7 +6696,11 @@ pub(crate) unsafe fn walk_ts_external_module_reference<'a, Tr: Traverse<'a>>(
     node: *mut TSExternalModuleReference<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSExternalModuleReference");
     traverser.enter_ts_external_module_reference(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSExternalModuleReferenceExpression(
         ancestor::TSExternalModuleReferenceWithoutExpression(node),
     ));
@@ -5330,6 +6711,8 @@ pub(crate) unsafe fn walk_ts_external_module_reference<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSExternalModuleReference");
     traverser.exit_ts_external_module_reference(&mut *node, ctx);
 }
 
@@ -5338,7 +6721,11 @@ pub(crate) unsafe fn walk_ts_non_null_expression<'a, Tr: Traverse<'a>>(
     node: *mut TSNonNullExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSNonNullExpression");
     traverser.enter_ts_non_null_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSNonNullExpressionExpression(
         ancestor::TSNonNullExpressionWithoutExpression(node),
     ));
@@ -5349,6 +6736,8 @@ pub(crate) unsafe fn walk_ts_non_null_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSNonNullExpression");
     traverser.exit_ts_non_null_expression(&mut *node, ctx);
 }
 
@@ -5357,7 +6746,11 @@ pub(crate) unsafe fn walk_decorator<'a, Tr: Traverse<'a>>(
     node: *mut Decorator<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("Decorator");
     traverser.enter_decorator(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::DecoratorExpression(ancestor::DecoratorWithoutExpression(node)));
     walk_expression(
         traverser,
@@ -5365,6 +6758,8 @@ pub(crate) unsafe fn walk_decorator<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "Decorator");
     traverser.exit_decorator(&mut *node, ctx);
 }
 
@@ -5373,7 +6768,11 @@ pub(crate) unsafe fn walk_ts_export_assignment<'a, Tr: Traverse<'a>>(
     node: *mut TSExportAssignment<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSExportAssignment");
     traverser.enter_ts_export_assignment(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSExportAssignmentExpression(
         ancestor::TSExportAssignmentWithoutExpression(node),
     ));
@@ -5383,6 +6782,8 @@ pub(crate) unsafe fn walk_ts_export_assignment<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSExportAssignment");
     traverser.exit_ts_export_assignment(&mut *node, ctx);
 }
 
@@ -5391,7 +6792,11 @@ pub(crate) unsafe fn walk_ts_namespace_export_declaration<'a, Tr: Traverse<'a>>(
     node: *mut TSNamespaceExportDeclaration<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSNamespaceExportDeclaration");
     traverser.enter_ts_namespace_export_declaration(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSNamespaceExportDeclarationId(
         ancestor::TSNamespaceExportDeclarationWithoutId(node),
     ));
@@ -5402,6 +6807,8 @@ pub(crate) unsafe fn walk_ts_namespace_export_declaration<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSNamespaceExportDeclaration");
     traverser.exit_ts_namespace_export_declaration(&mut *node, ctx);
 }
 
@@ -5410,7 +6817,11 @@ pub(crate) unsafe fn walk_ts_instantiation_expression<'a, Tr: Traverse<'a>>(
     node: *mut TSInstantiationExpression<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("TSInstantiationExpression");
     traverser.enter_ts_instantiation_expression(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::TSInstantiationExpressionExpression(
         ancestor::TSInstantiationExpressionWithoutExpression(node),
     ));
@@ -5428,6 +6839,8 @@ pub(crate) unsafe fn walk_ts_instantiation_expression<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "TSInstantiationExpression");
     traverser.exit_ts_instantiation_expression(&mut *node, ctx);
 }
 
@@ -5436,7 +6849,11 @@ pub(crate) unsafe fn walk_js_doc_nullable_type<'a, Tr: Traverse<'a>>(
     node: *mut JSDocNullableType<'a>,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSDocNullableType");
     traverser.enter_js_doc_nullable_type(&mut *node, ctx);
+    #[cfg(debug_assertions)]
+    let stack_depth = ctx.ancestors_depth();
     ctx.push_stack(Ancestor::JSDocNullableTypeTypeAnnotation(
         ancestor::JSDocNullableTypeWithoutTypeAnnotation(node),
     ));
@@ -5446,6 +6863,8 @@ pub(crate) unsafe fn walk_js_doc_nullable_type<'a, Tr: Traverse<'a>>(
         ctx,
     );
     ctx.pop_stack();
+    #[cfg(debug_assertions)]
+    ctx.assert_stack_balanced(stack_depth, "JSDocNullableType");
     traverser.exit_js_doc_nullable_type(&mut *node, ctx);
 }
 
@@ -5454,6 +6873,8 @@ pub(crate) unsafe fn walk_js_doc_unknown_type<'a, Tr: Traverse<'a>>(
     node: *mut JSDocUnknownType,
     ctx: &mut TraverseCtx<'a>,
 ) {
+    #[cfg(feature = "visit_counts")]
+    ctx.count_visit("JSDocUnknownType");
     traverser.enter_js_doc_unknown_type(&mut *node, ctx);
     traverser.exit_js_doc_unknown_type(&mut *node, ctx);
 }
@@ -0,0 +1,73 @@
+use std::{cell::RefCell, rc::Rc};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{BinaryExpression, NumericLiteral};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+/// Logs visits to numeric literals and binary expressions, prefixed with `name`.
+struct Logger {
+    name: &'static str,
+    log: Log,
+}
+
+impl Logger {
+    fn push(&self, event: &str) {
+        self.log.borrow_mut().push(format!("{} {event}", self.name));
+    }
+}
+
+impl<'a> Traverse<'a> for Logger {
+    fn enter_binary_expression(&mut self, _: &mut BinaryExpression<'a>, _: &mut TraverseCtx<'a>) {
+        self.push("enter binary");
+    }
+
+    fn exit_binary_expression(&mut self, _: &mut BinaryExpression<'a>, _: &mut TraverseCtx<'a>) {
+        self.push("exit binary");
+    }
+
+    fn enter_numeric_literal(&mut self, lit: &mut NumericLiteral<'a>, _: &mut TraverseCtx<'a>) {
+        self.push(&format!("enter {}", lit.value));
+    }
+}
+
+/// Doubles numeric literals.
+struct Doubler;
+
+impl<'a> Traverse<'a> for Doubler {
+    fn enter_numeric_literal(&mut self, lit: &mut NumericLiteral<'a>, _: &mut TraverseCtx<'a>) {
+        lit.value *= 2.0;
+    }
+}
+
+#[test]
+fn compose_visitors() {
+    let source_text = "1 + 2;";
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+
+    let log = Log::default();
+    let first = Logger { name: "first", log: Rc::clone(&log) };
+    let second = Logger { name: "second", log: Rc::clone(&log) };
+    // Nested tuple: `first`, then `Doubler`, then `second`
+    let mut traverser = (first, (Doubler, second));
+    traverse_mut(&mut traverser, &mut program, source_text, source_type, &allocator);
+
+    let expected = [
+        "first enter binary",
+        "second enter binary",
+        "first enter 1",
+        "second enter 2",
+        "first enter 2",
+        "second enter 4",
+        "first exit binary",
+        "second exit binary",
+    ];
+    assert_eq!(*log.borrow(), expected);
+}
@@ -0,0 +1,64 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{BindingPatternKind, IdentifierReference};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+#[derive(Default)]
+struct CollectParams {
+    /// `(reference name, names of enclosing function's params)`
+    params: Vec<(String, Option<Vec<String>>)>,
+}
+
+impl<'a> Traverse<'a> for CollectParams {
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let params = ctx.enclosing_function_params().map(|params| {
+            params
+                .items
+                .iter()
+                .map(|param| match &param.pattern.kind {
+                    BindingPatternKind::BindingIdentifier(ident) => ident.name.to_string(),
+                    _ => "<pattern>".to_string(),
+                })
+                .collect()
+        });
+        self.params.push((ident.name.to_string(), params));
+    }
+}
+
+#[test]
+fn enclosing_function_params() {
+    let source_text = "
+        top;
+        function f(a, b) { if (c) { { inBlock; } } }
+        const g = (d) => inArrow;
+        function h(e) { return () => inNestedArrow; }
+        function i(x = inParams) {}
+        const j = { m(y, { z }) { inMethod; } };
+    ";
+    let source_type = SourceType::default().with_module(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+
+    let mut collector = CollectParams::default();
+    traverse_mut(&mut collector, &mut program, source_text, source_type, &allocator);
+
+    let names = |names: &[&str]| Some(names.iter().map(ToString::to_string).collect());
+    let expected = [
+        ("top", None),
+        ("c", names(&["a", "b"])),
+        ("inBlock", names(&["a", "b"])),
+        ("inArrow", names(&["d"])),
+        ("inNestedArrow", names(&[])),
+        ("inParams", None),
+        ("inMethod", names(&["y", "<pattern>"])),
+    ];
+    let expected = expected.map(|(name, params)| (name.to_string(), params));
+    assert_eq!(collector.params, expected);
+}
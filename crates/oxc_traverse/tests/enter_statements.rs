@@ -0,0 +1,62 @@
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::ast::*;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, SPAN};
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+/// Inserts `debugger;` at the top of every statement list, and removes `drop();` statements.
+struct EditStatements;
+
+impl<'a> Traverse<'a> for EditStatements {
+    fn enter_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
+        stmts.retain(|stmt| !is_drop_call(stmt));
+        stmts.insert(0, ctx.ast.debugger_statement(SPAN));
+    }
+}
+
+fn is_drop_call(stmt: &Statement) -> bool {
+    let Statement::ExpressionStatement(stmt) = stmt else { return false };
+    matches!(&stmt.expression, Expression::CallExpression(call) if call.callee.is_specific_id("drop"))
+}
+
+fn edit(source_text: &str) -> String {
+    let source_type = SourceType::default().with_module(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+
+    traverse_mut(&mut EditStatements, &mut program, source_text, source_type, &allocator);
+
+    Codegen::<true>::new("", source_text, CodegenOptions::default()).build(&program).source_text
+}
+
+#[test]
+fn statements_in_program_and_function() {
+    assert_eq!(
+        edit("drop(); function f() { drop(); foo(); }"),
+        "debugger;function f(){debugger;foo()}"
+    );
+}
+
+#[test]
+fn statements_in_nested_block() {
+    assert_eq!(edit("if (a) { { drop(); foo(); } }"), "debugger;if(a){debugger;{debugger;foo()}}");
+}
+
+#[test]
+fn statements_in_switch_case() {
+    assert_eq!(
+        edit("switch (a) { case 1: drop(); foo(); default: }"),
+        "debugger;switch(a){case 1:debugger;foo();default:debugger}"
+    );
+}
+
+#[test]
+fn statements_in_static_block() {
+    assert_eq!(
+        edit("class C { static { drop(); foo(); } }"),
+        "debugger;class C{static{debugger;foo()}}"
+    );
+}
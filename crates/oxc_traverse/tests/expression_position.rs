@@ -0,0 +1,63 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{JSXElement, JSXElementName};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+#[derive(Default)]
+struct CollectJsxPositions {
+    /// `(element name, is_return_value, is_statement_expression)`
+    positions: Vec<(String, bool, bool)>,
+}
+
+impl<'a> Traverse<'a> for CollectJsxPositions {
+    fn enter_jsx_element(&mut self, elem: &mut JSXElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let JSXElementName::Identifier(ident) = &elem.opening_element.name else { return };
+        self.positions.push((
+            ident.name.to_string(),
+            ctx.is_return_value(),
+            ctx.is_statement_expression(),
+        ));
+    }
+}
+
+#[test]
+fn return_value_and_statement_expression() {
+    let source_text = "
+        function a() { return <Returned />; }
+        function b() { return (<Parenthesized />); }
+        const c = () => <ArrowBody />;
+        const d = () => { <InArrowBlock />; };
+        <Statement />;
+        (<ParenthesizedStatement />);
+        render(<Argument />);
+        function e() { return render(<ReturnedArgument />); }
+        const f = <Init />;
+    ";
+    let source_type = SourceType::default().with_module(true).with_jsx(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+
+    let mut collector = CollectJsxPositions::default();
+    traverse_mut(&mut collector, &mut program, source_text, source_type, &allocator);
+
+    let expected = [
+        ("Returned", true, false),
+        ("Parenthesized", true, false),
+        ("ArrowBody", true, false),
+        ("InArrowBlock", false, true),
+        ("Statement", false, true),
+        ("ParenthesizedStatement", false, true),
+        ("Argument", false, false),
+        ("ReturnedArgument", false, false),
+        ("Init", false, false),
+    ];
+    let positions = collector
+        .positions
+        .iter()
+        .map(|(name, returned, statement)| (name.as_str(), *returned, *statement))
+        .collect::<Vec<_>>();
+    assert_eq!(positions, expected);
+}
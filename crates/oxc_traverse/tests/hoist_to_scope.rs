@@ -0,0 +1,102 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::*;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, SPAN};
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+/// Replaces `hoist("x")` with `x`, and hoists `const x = 1;` to the enclosing scope.
+struct HoistConsts;
+
+impl<'a> Traverse<'a> for HoistConsts {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(call) = expr else { return };
+        if !call.callee.is_specific_id("hoist") {
+            return;
+        }
+        let Some(Argument::StringLiteral(name)) = call.arguments.first() else { return };
+        let name = name.value.clone();
+
+        let ident = BindingIdentifier::new(SPAN, name.clone());
+        let id = ctx.ast.binding_pattern(ctx.ast.binding_pattern_identifier(ident), None, false);
+        let init = ctx.ast.literal_number_expression(ctx.ast.number_literal(
+            SPAN,
+            1.0,
+            "1",
+            NumberBase::Decimal,
+        ));
+        let kind = VariableDeclarationKind::Const;
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, id, Some(init), false);
+        let declaration = ctx.ast.variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        );
+        ctx.hoist_to_scope(Statement::VariableDeclaration(declaration));
+
+        *expr = ctx.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name));
+    }
+}
+
+/// Hoists `debugger;` out of every function body after visiting it,
+/// as well as hoisting `const`s like [`HoistConsts`].
+struct HoistOnExit;
+
+impl<'a> Traverse<'a> for HoistOnExit {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        HoistConsts.enter_expression(expr, ctx);
+    }
+
+    fn exit_function_body(&mut self, _body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        let stmt = ctx.ast.debugger_statement(SPAN);
+        ctx.hoist_to_scope(stmt);
+    }
+}
+
+fn hoist(source_text: &str) -> String {
+    hoist_with(&mut HoistConsts, source_text)
+}
+
+fn hoist_with<Tr: for<'a> Traverse<'a>>(traverser: &mut Tr, source_text: &str) -> String {
+    let source_type = SourceType::default().with_module(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+
+    traverse_mut(traverser, &mut program, source_text, source_type, &allocator);
+
+    Codegen::<true>::new("", source_text, CodegenOptions::default()).build(&program).source_text
+}
+
+#[test]
+fn hoist_to_program() {
+    assert_eq!(hoist("foo(); bar(hoist('a'), hoist('b'));"), "const a=1;const b=1;foo();bar(a,b);");
+}
+
+#[test]
+fn hoist_to_function() {
+    assert_eq!(
+        hoist("'use strict'; function f() { 'use strict'; foo(hoist('a')); } bar;"),
+        "'use strict';function f(){'use strict';const a=1;foo(a)}bar;"
+    );
+    assert_eq!(hoist("foo(() => { bar(hoist('a')); });"), "foo(()=>{const a=1;bar(a)});");
+}
+
+#[test]
+fn hoist_from_arrow_expression_body() {
+    assert_eq!(
+        hoist("function f() { return () => hoist('a'); }"),
+        "function f(){const a=1;return ()=>a}"
+    );
+}
+
+#[test]
+fn hoist_from_exit_function_body() {
+    assert_eq!(
+        hoist_with(&mut HoistOnExit, "function f() { function g() { hoist('a'); } }"),
+        "debugger;function f(){debugger;function g(){const a=1;a}}"
+    );
+}
@@ -0,0 +1,59 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::IdentifierReference;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+#[derive(Default)]
+struct CollectJsxPositions {
+    /// `(identifier name, in_jsx_element, in_jsx_attribute)`
+    positions: Vec<(String, bool, bool)>,
+}
+
+impl<'a> Traverse<'a> for CollectJsxPositions {
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.positions.push((ident.name.to_string(), ctx.in_jsx_element(), ctx.in_jsx_attribute()));
+    }
+}
+
+#[test]
+fn in_jsx_element_and_attribute() {
+    let source_text = "
+        outside;
+        <div id={attr} {...spread} render={() => <i>{inAttrChild}</i>}>
+            {child}
+            <span>{nested}</span>
+        </div>;
+        <>{fragmentChild}</>;
+        (() => afterJsx)();
+    ";
+    let source_type = SourceType::default().with_module(true).with_jsx(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+
+    let mut collector = CollectJsxPositions::default();
+    traverse_mut(&mut collector, &mut program, source_text, source_type, &allocator);
+
+    let expected = [
+        ("outside", false, false),
+        ("attr", true, true),
+        ("spread", true, true),
+        ("inAttrChild", true, true),
+        ("child", true, false),
+        ("nested", true, false),
+        ("fragmentChild", true, false),
+        ("afterJsx", false, false),
+    ];
+    let positions = collector
+        .positions
+        .iter()
+        .map(|(name, element, attribute)| (name.as_str(), *element, *attribute))
+        .collect::<Vec<_>>();
+    assert_eq!(positions, expected);
+}
@@ -0,0 +1,70 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::IdentifierReference;
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+use oxc_traverse::{traverse_mut, JsxSpans, Traverse, TraverseCtx};
+
+fn span_of(source_text: &str, code: &str) -> Span {
+    let start = source_text.find(code).unwrap();
+    #[allow(clippy::cast_possible_truncation)]
+    Span::new(start as u32, (start + code.len()) as u32)
+}
+
+#[test]
+fn nested_jsx_spans() {
+    let source_text = "
+        const a = <div id={<b />}>
+            <>text<i /></>
+            {list.map(() => <li />)}
+        </div>;
+        const b = 1;
+    ";
+    let source_type = SourceType::default().with_module(true).with_jsx(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+
+    let mut jsx_spans = JsxSpans::default();
+    traverse_mut(&mut jsx_spans, &mut program, source_text, source_type, &allocator);
+
+    let div_end = source_text.find("</div>").unwrap() + "</div>".len();
+    #[allow(clippy::cast_possible_truncation)]
+    let div = Span::new(span_of(source_text, "<div").start, div_end as u32);
+    let expected = [
+        div,
+        span_of(source_text, "<b />"),
+        span_of(source_text, "<>text<i /></>"),
+        span_of(source_text, "<i />"),
+        span_of(source_text, "<li />"),
+    ];
+    assert_eq!(jsx_spans.spans, expected);
+}
+
+/// Counts identifier references, to run in the same walk as `JsxSpans`
+#[derive(Default)]
+struct CountReferences(usize);
+
+impl<'a> Traverse<'a> for CountReferences {
+    fn enter_identifier_reference(
+        &mut self,
+        _ident: &mut IdentifierReference<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.0 += 1;
+    }
+}
+
+#[test]
+fn jsx_spans_with_other_traverser() {
+    let source_text = "<div>{a}</div>;";
+    let source_type = SourceType::default().with_module(true).with_jsx(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+
+    let mut traverser = (JsxSpans::default(), CountReferences::default());
+    traverse_mut(&mut traverser, &mut program, source_text, source_type, &allocator);
+    assert_eq!(traverser.0.spans, [Span::new(0, 14)]);
+    assert_eq!(traverser.1 .0, 1);
+}
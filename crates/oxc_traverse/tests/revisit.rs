@@ -0,0 +1,80 @@
+#![cfg(feature = "revisit")]
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::*;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+/// Replaces `unwrap(x)` with `x`.
+struct Unwrap {
+    revisit: bool,
+}
+
+impl<'a> Traverse<'a> for Unwrap {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::CallExpression(call) = expr else { return };
+        if !call.callee.is_specific_id("unwrap") {
+            return;
+        }
+        let Some(arg) = call.arguments.first_mut().and_then(Argument::as_expression_mut) else {
+            return;
+        };
+        *expr = ctx.ast.move_expression(arg);
+        if self.revisit {
+            ctx.revisit();
+        }
+    }
+}
+
+fn unwrap(source_text: &str, revisit: bool) -> String {
+    let source_type = SourceType::default().with_module(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+
+    traverse_mut(&mut Unwrap { revisit }, &mut program, source_text, source_type, &allocator);
+
+    Codegen::<true>::new("", source_text, CodegenOptions::default()).build(&program).source_text
+}
+
+#[test]
+fn replacement_is_not_revisited_by_default() {
+    // The replacement `unwrap(unwrap(x))` is not entered, but its argument is
+    assert_eq!(unwrap("unwrap(unwrap(unwrap(x)));", false), "unwrap(x);");
+}
+
+#[test]
+fn replacement_is_revisited() {
+    assert_eq!(unwrap("unwrap(unwrap(unwrap(x)));", true), "x;");
+    assert_eq!(unwrap("foo(unwrap(unwrap(x)), unwrap(y));", true), "foo(x,y);");
+}
+
+/// Replaces an expression with itself forever.
+#[derive(Default)]
+struct RevisitForever {
+    enters: usize,
+}
+
+impl<'a> Traverse<'a> for RevisitForever {
+    fn enter_expression(&mut self, _expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.enters += 1;
+        ctx.revisit();
+    }
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "Node was revisited 32 times"))]
+fn revisits_are_limited() {
+    let source_text = "x;";
+    let source_type = SourceType::default().with_module(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+
+    let mut traverser = RevisitForever::default();
+    traverse_mut(&mut traverser, &mut program, source_text, source_type, &allocator);
+    assert_eq!(traverser.enters, 33);
+}
@@ -0,0 +1,63 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::*;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+/// Rewrites imports of `from` to import `to` instead, and counts the rewritten declarations on exit.
+struct RewriteImportSource {
+    from: &'static str,
+    to: &'static str,
+    rewritten: usize,
+}
+
+impl<'a> Traverse<'a> for RewriteImportSource {
+    fn enter_import_declaration(
+        &mut self,
+        decl: &mut ImportDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if decl.source.value == self.from {
+            decl.source.value = ctx.ast.new_atom(self.to);
+        }
+    }
+
+    fn exit_import_declaration(
+        &mut self,
+        decl: &mut ImportDeclaration<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if decl.source.value == self.to {
+            self.rewritten += 1;
+        }
+    }
+}
+
+#[test]
+fn rewrite_import_source() {
+    let source_text = "
+        import { jsx as _jsx } from 'react/jsx-runtime';
+        import React from 'react';
+        import 'react/jsx-runtime';
+        _jsx(React.Fragment, {});
+    ";
+    let source_type = SourceType::default().with_module(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+
+    let mut rewriter =
+        RewriteImportSource { from: "react/jsx-runtime", to: "./jsx-shim", rewritten: 0 };
+    traverse_mut(&mut rewriter, &mut program, source_text, source_type, &allocator);
+    assert_eq!(rewriter.rewritten, 2);
+
+    let printed = Codegen::<true>::new("", source_text, CodegenOptions::default())
+        .build(&program)
+        .source_text;
+    assert_eq!(
+        printed,
+        "import {jsx as _jsx} from './jsx-shim';import React from 'react';import './jsx-shim';_jsx(React.Fragment,{});"
+    );
+}
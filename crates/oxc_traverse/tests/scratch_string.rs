@@ -0,0 +1,48 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::StringLiteral;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+/// Doubles each string, recording whether the scratch string was reused.
+#[derive(Default)]
+struct DoubleStrings {
+    /// Capacity of the scratch string when taken, for each string literal
+    capacities: Vec<usize>,
+}
+
+impl<'a> Traverse<'a> for DoubleStrings {
+    fn enter_string_literal(&mut self, lit: &mut StringLiteral<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut doubled = ctx.take_scratch_string();
+        assert!(doubled.is_empty());
+        self.capacities.push(doubled.capacity());
+        doubled.push_str(&lit.value);
+        doubled.push_str(&lit.value);
+        lit.value = ctx.ast.new_atom(&doubled);
+        ctx.recycle_scratch_string(doubled);
+    }
+}
+
+#[test]
+fn scratch_string() {
+    let source_text = "f('abc', 'de', 'fghi');";
+    let source_type = SourceType::default();
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+
+    let mut traverser = DoubleStrings::default();
+    traverse_mut(&mut traverser, &mut program, source_text, source_type, &allocator);
+
+    let printed =
+        Codegen::<true>::new("", "", CodegenOptions::default()).build(&program).source_text;
+    assert_eq!(printed, "f('abcabc','dede','fghifghi');");
+
+    // The string is only allocated the first time, and is reused after that
+    let [first, second, third] = traverser.capacities[..] else { panic!() };
+    assert_eq!(first, 0);
+    assert!(second >= 6);
+    assert_eq!(third, second);
+}
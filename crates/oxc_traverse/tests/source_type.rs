@@ -0,0 +1,41 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::JSXElement;
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
+
+#[derive(Default)]
+struct CollectSourceType {
+    /// `(is_typescript, program span)` seen in each JSX element
+    seen: Vec<(bool, Span)>,
+}
+
+impl<'a> Traverse<'a> for CollectSourceType {
+    fn enter_jsx_element(&mut self, _elem: &mut JSXElement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.seen.push((ctx.source_type().is_typescript(), ctx.program_span()));
+    }
+}
+
+fn collect(source_text: &str, source_type: SourceType) -> Vec<(bool, Span)> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+
+    let mut collector = CollectSourceType::default();
+    traverse_mut(&mut collector, &mut program, source_text, source_type, &allocator);
+    collector.seen
+}
+
+#[test]
+fn source_type_and_program_span() {
+    let source_text = "<div><span /></div>;";
+    #[allow(clippy::cast_possible_truncation)]
+    let span = Span::new(0, source_text.len() as u32);
+
+    let jsx = SourceType::default().with_module(true).with_jsx(true);
+    assert_eq!(collect(source_text, jsx), [(false, span), (false, span)]);
+
+    let tsx = jsx.with_typescript(true);
+    assert_eq!(collect(source_text, tsx), [(true, span), (true, span)]);
+}
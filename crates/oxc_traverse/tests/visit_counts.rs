@@ -0,0 +1,42 @@
+#![cfg(feature = "visit_counts")]
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_traverse::{traverse_mut_with_visit_counts, Traverse};
+
+struct Noop;
+
+impl<'a> Traverse<'a> for Noop {}
+
+#[test]
+fn visit_counts() {
+    let source_text = "const a = <div><Foo x={1} /><Foo /></div>; f(a, 2);";
+    let source_type = SourceType::default().with_module(true).with_jsx(true);
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+
+    let counts = traverse_mut_with_visit_counts(
+        &mut Noop,
+        &mut program,
+        source_text,
+        source_type,
+        &allocator,
+    );
+
+    assert_eq!(counts.get("Program"), 1);
+    assert_eq!(counts.get("VariableDeclaration"), 1);
+    assert_eq!(counts.get("ExpressionStatement"), 1);
+    assert_eq!(counts.get("CallExpression"), 1);
+    assert_eq!(counts.get("JSXElement"), 3);
+    assert_eq!(counts.get("JSXOpeningElement"), 3);
+    assert_eq!(counts.get("JSXClosingElement"), 1);
+    assert_eq!(counts.get("JSXAttribute"), 1);
+    assert_eq!(counts.get("NumericLiteral"), 2);
+    assert_eq!(counts.get("IdentifierReference"), 2);
+    // Enums are not counted
+    assert_eq!(counts.get("Expression"), 0);
+    assert_eq!(counts.total(), counts.iter().map(|(_, count)| count).sum::<usize>());
+}
@@ -0,0 +1,72 @@
+//! Check the generated code covers every type marked `#[visited_node]`.
+//!
+//! `scripts/build.mjs` generates `walk.rs` and `traverse.rs` from the types in `oxc_ast`
+//! with the attribute. `build.rs` runs it whenever the AST changes, except on CI, which uses
+//! the checked-in files, so a type which is annotated but not traversed means they are out of date.
+
+const AST_FILES: [&str; 4] = [
+    include_str!("../../oxc_ast/src/ast/js.rs"),
+    include_str!("../../oxc_ast/src/ast/jsx.rs"),
+    include_str!("../../oxc_ast/src/ast/literal.rs"),
+    include_str!("../../oxc_ast/src/ast/ts.rs"),
+];
+const WALK: &str = include_str!("../src/walk.rs");
+const TRAVERSE: &str = include_str!("../src/traverse.rs");
+
+/// Names of the types marked `#[visited_node]` in `code`.
+fn visited_nodes(code: &str) -> Vec<&str> {
+    let mut names = vec![];
+    let mut lines = code.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if !line.starts_with("#[visited_node") {
+            continue;
+        }
+        let item = lines
+            .find_map(|line| {
+                line.strip_prefix("pub struct ").or_else(|| line.strip_prefix("pub enum "))
+            })
+            .expect("`#[visited_node]` should be followed by a struct or enum");
+        let end = item.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(item.len());
+        names.push(&item[..end]);
+    }
+    names
+}
+
+/// Same as `camelToSnake` in `scripts/lib/utils.mjs`, e.g. `JSXElement` -> `jsx_element`.
+fn camel_to_snake(name: &str) -> String {
+    let prefix_len =
+        ["TS", "JSX", "JS"].iter().find(|prefix| name.starts_with(*prefix)).map_or(1, |p| p.len());
+    let mut snake = name[..prefix_len].to_lowercase();
+    for c in name[prefix_len..].chars() {
+        if c.is_ascii_uppercase() {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+#[test]
+fn every_visited_node_is_traversed() {
+    let names = AST_FILES.iter().flat_map(|code| visited_nodes(code)).collect::<Vec<_>>();
+    assert!(names.contains(&"Program") && names.contains(&"JSXElement"));
+
+    let missing = names
+        .iter()
+        .filter(|name| {
+            let snake = camel_to_snake(name);
+            !WALK.contains(&format!("fn walk_{snake}<"))
+                || !TRAVERSE.contains(&format!("fn enter_{snake}("))
+                || !TRAVERSE.contains(&format!("fn exit_{snake}("))
+        })
+        .collect::<Vec<_>>();
+    assert!(missing.is_empty(), "Not traversed, re-run `scripts/build.mjs`: {missing:?}");
+}
+
+#[test]
+fn camel_to_snake_prefixes() {
+    assert_eq!(camel_to_snake("Program"), "program");
+    assert_eq!(camel_to_snake("JSXElement"), "jsx_element");
+    assert_eq!(camel_to_snake("TSTypeAnnotation"), "ts_type_annotation");
+    assert_eq!(camel_to_snake("JSXText"), "jsx_text");
+}
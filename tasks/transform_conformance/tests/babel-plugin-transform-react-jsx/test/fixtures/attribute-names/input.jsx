@@ -0,0 +1,2 @@
+var a = <label className="a" htmlFor="b" tabIndex={1} />;
+var b = <div className={cls} {...props} data-id="x" />;
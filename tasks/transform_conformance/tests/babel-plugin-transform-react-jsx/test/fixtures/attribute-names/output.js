@@ -0,0 +1,11 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+var a = _jsx("label", {
+  class: "a",
+  for: "b",
+  "tab-index": 1
+});
+var b = _jsx("div", {
+  class: cls,
+  ...props,
+  "data-id": "x"
+});
@@ -0,0 +1,16 @@
+var a = <div>
+	<span />
+	hello	world
+</div>;
+
+var b = <p>
+  one 
+  two&amp;three
+</p>;
+
+var c = <i>
+
+  a
+
+  b
+</i>;
@@ -0,0 +1,10 @@
+import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+var a = _jsxs("div", {
+  children: [_jsx("span", {}), "hello world"]
+});
+var b = _jsx("p", {
+  children: "one\u00A0 two&three"
+});
+var c = _jsx("i", {
+  children: "a b"
+});
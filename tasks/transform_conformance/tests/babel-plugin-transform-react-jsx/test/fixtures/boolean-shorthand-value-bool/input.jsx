@@ -0,0 +1 @@
+var x = <input disabled data-foo xlink:href checked={false} />;
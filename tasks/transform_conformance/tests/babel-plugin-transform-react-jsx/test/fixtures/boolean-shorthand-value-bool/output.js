@@ -0,0 +1,7 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+var x = _jsx("input", {
+  disabled: true,
+  "data-foo": true,
+  "xlink:href": true,
+  checked: false
+});
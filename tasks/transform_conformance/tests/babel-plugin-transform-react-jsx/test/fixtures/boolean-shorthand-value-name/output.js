@@ -0,0 +1,7 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+var x = _jsx("input", {
+  disabled: "disabled",
+  "data-foo": "data-foo",
+  "xlink:href": "xlink:href",
+  checked: false
+});
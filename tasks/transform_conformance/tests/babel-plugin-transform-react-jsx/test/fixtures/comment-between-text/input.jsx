@@ -0,0 +1,7 @@
+var a = <p>a{/* c */}b</p>;
+var b = <p>
+  a
+  {/* c */}
+  b
+</p>;
+var c = <p>a {/* c */} b</p>;
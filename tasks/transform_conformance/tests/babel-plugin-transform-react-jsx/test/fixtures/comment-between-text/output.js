@@ -0,0 +1,10 @@
+import { jsxs as _jsxs } from "react/jsx-runtime";
+var a = _jsxs("p", {
+  children: ["a", "b"]
+});
+var b = _jsxs("p", {
+  children: ["a", "b"]
+});
+var c = _jsxs("p", {
+  children: ["a ", " b"]
+});
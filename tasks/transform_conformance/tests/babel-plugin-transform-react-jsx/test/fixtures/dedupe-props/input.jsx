@@ -0,0 +1,4 @@
+var a = <div a="1" a="2" />;
+var b = <div a="1" {...props} a="2" b />;
+var c = <div a={f()} a="2" />;
+var d = <div children="text" {...props}>child</div>;
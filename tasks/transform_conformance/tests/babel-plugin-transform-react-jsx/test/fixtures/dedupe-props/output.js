@@ -0,0 +1,17 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+var a = _jsx("div", {
+  a: "2"
+});
+var b = _jsx("div", {
+  ...props,
+  a: "2",
+  b: true
+});
+var c = _jsx("div", {
+  a: f(),
+  a: "2"
+});
+var d = _jsx("div", {
+  ...props,
+  children: "child"
+});
@@ -0,0 +1,5 @@
+var a = <Item key={id} value={v} />;
+var b = <Item value={v} key={computeKey()}>
+  <A />
+  <B />
+</Item>;
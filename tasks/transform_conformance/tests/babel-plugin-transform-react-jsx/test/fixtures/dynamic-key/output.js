@@ -0,0 +1,8 @@
+import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+var a = _jsx(Item, {
+  value: v
+}, id);
+var b = _jsxs(Item, {
+  value: v,
+  children: [_jsx(A, {}), _jsx(B, {})]
+}, computeKey());
@@ -0,0 +1,6 @@
+import { Fragment as _Fragment, jsx as _jsx } from "react/jsx-runtime";
+var a = _jsx(_Fragment, {});
+var b = _jsx(_Fragment, {});
+var c = _jsx(_Fragment, {
+  children: _jsx(_Fragment, {})
+});
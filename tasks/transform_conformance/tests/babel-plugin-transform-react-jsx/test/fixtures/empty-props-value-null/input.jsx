@@ -0,0 +1,3 @@
+var a = <div />;
+var b = <div>a<span /></div>;
+var c = <div id="x" />;
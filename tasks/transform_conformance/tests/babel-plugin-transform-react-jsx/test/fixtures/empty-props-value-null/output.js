@@ -0,0 +1,5 @@
+var a = React.createElement("div", null);
+var b = React.createElement("div", null, "a", React.createElement("span", null));
+var c = React.createElement("div", {
+  id: "x"
+});
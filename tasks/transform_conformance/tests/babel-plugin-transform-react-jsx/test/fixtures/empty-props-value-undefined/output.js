@@ -0,0 +1,5 @@
+var a = React.createElement("div", void 0);
+var b = React.createElement("div", void 0, "a", React.createElement("span", void 0));
+var c = React.createElement("div", {
+  id: "x"
+});
@@ -0,0 +1 @@
+ui.factory.createElement(ui.factory.Fragment, null, ui.factory.createElement("div", null));
@@ -0,0 +1,5 @@
+<div className="a">
+  <>
+    <Foo />
+  </>
+</div>;
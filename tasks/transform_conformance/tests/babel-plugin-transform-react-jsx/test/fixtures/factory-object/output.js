@@ -0,0 +1,3 @@
+jsx.createElement("div", {
+  className: "a"
+}, jsx.createElement(jsx.Fragment, null, jsx.createElement(Foo, null)));
@@ -0,0 +1,5 @@
+var a = <div>a<>b<c /></></div>;
+var b = <div><><>{x}</></></div>;
+var c = <><>a</><b /></>;
+var d = <div><React.Fragment key="k">a</React.Fragment></div>;
+var e = <>a</>;
@@ -0,0 +1,18 @@
+import { jsx as _jsx, jsxs as _jsxs, Fragment as _Fragment } from "react/jsx-runtime";
+var a = _jsxs("div", {
+  children: ["a", "b", _jsx("c", {})]
+});
+var b = _jsx("div", {
+  children: x
+});
+var c = _jsxs(_Fragment, {
+  children: ["a", _jsx("b", {})]
+});
+var d = _jsx("div", {
+  children: _jsx(React.Fragment, {
+    children: "a"
+  }, "k")
+});
+var e = _jsx(_Fragment, {
+  children: "a"
+});
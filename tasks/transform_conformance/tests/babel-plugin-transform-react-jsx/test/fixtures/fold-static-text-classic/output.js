@@ -0,0 +1,4 @@
+var a = React.createElement("p", null, "Hello World");
+var b = React.createElement("p", null, "a", b, "c");
+var c = React.createElement("p", null, "xy", React.createElement("br", null), "z", `t`);
+var d = React.createElement("p", null, "onetwo");
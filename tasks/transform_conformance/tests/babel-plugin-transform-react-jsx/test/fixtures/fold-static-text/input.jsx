@@ -0,0 +1,4 @@
+var a = <p>Hello{" "}World</p>;
+var b = <p>a{b}c</p>;
+var c = <p>{"x"}{'y'}<br />z{`t`}</p>;
+var d = <p>one{/* empty */}two</p>;
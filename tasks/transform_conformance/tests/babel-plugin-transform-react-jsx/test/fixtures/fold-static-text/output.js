@@ -0,0 +1,13 @@
+import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+var a = _jsx("p", {
+  children: "Hello World"
+});
+var b = _jsxs("p", {
+  children: ["a", b, "c"]
+});
+var c = _jsxs("p", {
+  children: ["xy", _jsx("br", {}), "z", `t`]
+});
+var d = _jsx("p", {
+  children: "onetwo"
+});
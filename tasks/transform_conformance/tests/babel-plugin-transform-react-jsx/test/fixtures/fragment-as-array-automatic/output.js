@@ -0,0 +1,10 @@
+import { jsx as _jsx, Fragment as _Fragment, jsxs as _jsxs } from "react/jsx-runtime";
+var a = _jsxs(_Fragment, {
+  children: [_jsx("div", {}), _jsx(Foo, {})]
+});
+var b = _jsx(_Fragment, {});
+var c = _jsx("div", {
+  children: _jsx(_Fragment, {
+    children: "text"
+  })
+});
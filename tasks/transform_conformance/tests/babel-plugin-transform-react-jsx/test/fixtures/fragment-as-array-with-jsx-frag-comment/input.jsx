@@ -0,0 +1,4 @@
+/** @jsxFrag Fragment */
+var a = <><div /><Foo /></>;
+var b = <></>;
+var c = <div><>text</></div>;
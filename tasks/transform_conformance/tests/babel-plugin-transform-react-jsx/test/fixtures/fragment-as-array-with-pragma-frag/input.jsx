@@ -0,0 +1,3 @@
+var a = <><div /><Foo /></>;
+var b = <></>;
+var c = <div><>text</></div>;
@@ -0,0 +1,3 @@
+var a = React.createElement(Fragment, null, React.createElement("div", null), React.createElement(Foo, null));
+var b = React.createElement(Fragment, null);
+var c = React.createElement("div", null, React.createElement(Fragment, null, "text"));
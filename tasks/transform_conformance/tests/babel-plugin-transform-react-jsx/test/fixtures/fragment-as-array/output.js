@@ -0,0 +1,3 @@
+var a = [React.createElement("div", null), React.createElement(Foo, null)];
+var b = [];
+var c = React.createElement("div", null, ["text"]);
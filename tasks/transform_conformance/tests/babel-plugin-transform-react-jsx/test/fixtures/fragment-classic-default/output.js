@@ -0,0 +1,3 @@
+var a = React.createElement(React.Fragment, null, React.createElement("div", null), React.createElement(Foo, null));
+var b = React.createElement(React.Fragment, null);
+var c = React.createElement("div", null, React.createElement(React.Fragment, null, "text"));
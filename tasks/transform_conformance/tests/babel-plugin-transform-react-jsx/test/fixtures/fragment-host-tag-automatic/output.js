@@ -0,0 +1,13 @@
+import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+var a = _jsx("template", {
+  children: _jsx("a", {})
+});
+var b = _jsxs("template", {
+  children: [_jsx("a", {}), _jsx("b", {})]
+});
+var c = _jsx("div", {
+  children: _jsx("template", {
+    children: "text"
+  })
+});
+var d = _jsx("template", {});
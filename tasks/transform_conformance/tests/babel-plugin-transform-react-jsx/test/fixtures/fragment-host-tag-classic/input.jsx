@@ -0,0 +1,7 @@
+var a = <><a /></>;
+var b = <>
+  <a />
+  <b />
+</>;
+var c = <div><>text</></div>;
+var d = <></>;
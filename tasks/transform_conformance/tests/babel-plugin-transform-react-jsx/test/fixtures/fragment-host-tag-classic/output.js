@@ -0,0 +1,4 @@
+var a = React.createElement("template", null, React.createElement("a", null));
+var b = React.createElement("template", null, React.createElement("a", null), React.createElement("b", null));
+var c = React.createElement("div", null, React.createElement("template", null, "text"));
+var d = React.createElement("template", null);
@@ -0,0 +1,5 @@
+function Component({ id }) {
+  const a = <div className="x" />;
+  const b = <div className={id} />;
+  return <ul><li>text</li></ul>;
+}
@@ -0,0 +1,16 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+const _ref = __track(_jsx("div", {
+  className: "x"
+}));
+const _ref2 = __track(_jsx("ul", {
+  children: __track(_jsx("li", {
+    children: "text"
+  }))
+}));
+function Component({ id }) {
+  const a = _ref;
+  const b = __track(_jsx("div", {
+    className: id
+  }));
+  return _ref2;
+}
@@ -0,0 +1,11 @@
+const top = <div className="top" />;
+
+function Component({ id }) {
+  const a = <div className="x" />;
+  const b = <div className={id} />;
+  const c = <ul>text<li tabIndex={1}>{"item"}</li></ul>;
+  const d = <div ref={id} />;
+  return <Child />;
+}
+
+const Arrow = () => <span hidden />;
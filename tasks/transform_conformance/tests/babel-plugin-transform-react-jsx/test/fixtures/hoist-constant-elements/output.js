@@ -0,0 +1,28 @@
+import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+const _ref = _jsx("div", {
+  className: "x"
+});
+const _ref2 = _jsxs("ul", {
+  children: ["text", _jsx("li", {
+    tabIndex: 1,
+    children: "item"
+  })]
+});
+const _ref3 = _jsx("span", {
+  hidden: true
+});
+const top = _jsx("div", {
+  className: "top"
+});
+function Component({ id }) {
+  const a = _ref;
+  const b = _jsx("div", {
+    className: id
+  });
+  const c = _ref2;
+  const d = _jsx("div", {
+    ref: id
+  });
+  return _jsx(Child, {});
+}
+const Arrow = () => _ref3;
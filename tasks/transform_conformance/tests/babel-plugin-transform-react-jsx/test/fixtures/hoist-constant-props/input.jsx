@@ -0,0 +1,11 @@
+const top = <div className="top" />;
+
+function Component({ id }) {
+  const a = <div className="x" tabIndex={1} hidden />;
+  const b = <div className={id} />;
+  const c = <Foo title="t">text</Foo>;
+  const d = <div title="t"><span /></div>;
+  const e = <div />;
+  const f = <div {...{ id: "x" }} />;
+  return a;
+}
@@ -0,0 +1,30 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+const _props = {
+  className: "x",
+  tabIndex: 1,
+  hidden: true
+};
+const _props2 = {
+  title: "t",
+  children: "text"
+};
+const _props3 = {
+  id: "x"
+};
+const top = _jsx("div", {
+  className: "top"
+});
+function Component({ id }) {
+  const a = _jsx("div", _props);
+  const b = _jsx("div", {
+    className: id
+  });
+  const c = _jsx(Foo, _props2);
+  const d = _jsx("div", {
+    title: "t",
+    children: _jsx("span", {})
+  });
+  const e = _jsx("div", {});
+  const f = _jsx("div", _props3);
+  return a;
+}
@@ -0,0 +1,3 @@
+var list = <ul class="list"><li>a</li><><li>b</li><>c</></></ul>;
+var empty = <div />;
+var items = <><span /><span /></>;
@@ -0,0 +1,5 @@
+var list = h("ul", {
+  class: "list"
+}, h("li", void 0, "a"), h("li", void 0, "b"), "c");
+var empty = h("div", void 0);
+var items = [h("span", void 0), h("span", void 0)];
@@ -0,0 +1,3 @@
+var a = <div />;
+var b = <><span /><span /></>;
+var c = <div {...props} key="k" />;
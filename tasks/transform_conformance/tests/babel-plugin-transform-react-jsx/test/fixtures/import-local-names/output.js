@@ -0,0 +1,10 @@
+import { jsx as __oxcJsx, Fragment as __oxcFragment, jsxs as _jsxs } from "react/jsx-runtime";
+import { createElement as __oxcCreateElement } from "react";
+var a = __oxcJsx("div", {});
+var b = _jsxs(__oxcFragment, {
+  children: [__oxcJsx("span", {}), __oxcJsx("span", {})]
+});
+var c = __oxcCreateElement("div", {
+  ...props,
+  key: "k"
+});
@@ -0,0 +1,4 @@
+var x = <>
+  <div />
+  <span {...props} key="k" />
+</>;
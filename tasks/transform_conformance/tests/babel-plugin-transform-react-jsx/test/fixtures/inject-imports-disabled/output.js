@@ -0,0 +1,6 @@
+var x = _jsxs(_Fragment, {
+  children: [_jsx("div", {}), _createElement("span", {
+    ...props,
+    key: "k"
+  })]
+});
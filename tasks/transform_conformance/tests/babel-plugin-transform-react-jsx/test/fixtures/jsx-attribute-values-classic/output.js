@@ -0,0 +1,6 @@
+var a = React.createElement(Comp, {
+  title: React.createElement("b", null, "hi")
+});
+var b = React.createElement(Comp, {
+  title: React.createElement(React.Fragment, null, "hi")
+});
@@ -0,0 +1,2 @@
+var a = <Comp title=<b>hi</b> />;
+var b = <Comp title=<>hi</> />;
@@ -0,0 +1,11 @@
+import { jsx as _jsx, Fragment as _Fragment } from "react/jsx-runtime";
+var a = _jsx(Comp, {
+  title: _jsx("b", {
+    children: "hi"
+  })
+});
+var b = _jsx(Comp, {
+  title: _jsx(_Fragment, {
+    children: "hi"
+  })
+});
@@ -0,0 +1,5 @@
+var a = cond?.() ?? <Fallback />;
+var b = maybe?.render(<Child />);
+var c = enabled && <Feature />;
+var d = disabled || <Placeholder />;
+var e = obj?.[<Key />];
@@ -0,0 +1,6 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+var a = cond?.() ?? _jsx(Fallback, {});
+var b = maybe?.render(_jsx(Child, {}));
+var c = enabled && _jsx(Feature, {});
+var d = disabled || _jsx(Placeholder, {});
+var e = obj?.[_jsx(Key, {})];
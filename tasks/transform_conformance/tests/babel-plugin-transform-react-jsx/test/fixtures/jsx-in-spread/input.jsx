@@ -0,0 +1,5 @@
+var a = { ...{ el: <Foo /> } };
+var b = [...[<Foo />]];
+var c = f(...[<Foo />]);
+var d = <div {...{ el: <Foo /> }} />;
+var e = <div {...props}>{[...[<Foo />]]}</div>;
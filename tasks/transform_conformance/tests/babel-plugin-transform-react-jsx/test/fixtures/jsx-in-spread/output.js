@@ -0,0 +1,15 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+var a = {
+  ...{
+    el: _jsx(Foo, {})
+  }
+};
+var b = [...[_jsx(Foo, {})]];
+var c = f(...[_jsx(Foo, {})]);
+var d = _jsx("div", {
+  el: _jsx(Foo, {})
+});
+var e = _jsx("div", {
+  ...props,
+  children: [...[_jsx(Foo, {})]]
+});
@@ -0,0 +1,17 @@
+function C(x) {
+  switch (x) {
+    case 1:
+      return <A />;
+    default:
+      return <B />;
+  }
+}
+function D() {
+  try {
+    return <A />;
+  } catch {
+    return <B />;
+  } finally {
+    log(<C />);
+  }
+}
@@ -0,0 +1,18 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+function C(x) {
+  switch (x) {
+    case 1:
+      return _jsx(A, {});
+    default:
+      return _jsx(B, {});
+  }
+}
+function D() {
+  try {
+    return _jsx(A, {});
+  } catch {
+    return _jsx(B, {});
+  } finally {
+    log(_jsx(C, {}));
+  }
+}
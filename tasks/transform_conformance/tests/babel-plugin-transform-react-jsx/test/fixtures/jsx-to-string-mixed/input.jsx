@@ -0,0 +1,6 @@
+var a = <ul id={id}>
+  <li>One</li>
+  <li>{two}</li>
+  a &lt; b
+</ul>;
+var b = <Foo><p>static</p></Foo>;
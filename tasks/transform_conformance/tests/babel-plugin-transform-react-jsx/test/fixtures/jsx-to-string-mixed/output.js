@@ -0,0 +1,10 @@
+import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+var a = _jsxs("ul", {
+  id: id,
+  children: ["<li>One</li>", _jsx("li", {
+    children: two
+  }), "a &lt; b"]
+});
+var b = _jsx(Foo, {
+  children: "<p>static</p>"
+});
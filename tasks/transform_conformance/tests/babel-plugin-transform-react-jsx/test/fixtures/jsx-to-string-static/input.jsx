@@ -0,0 +1,8 @@
+var a = <div className="card" data-x="&quot;1&quot;">
+  <h1>Tom &amp; Jerry</h1>
+  <input disabled />
+  <br />
+  {/* comment */}
+  <>a &lt; b</>
+</div>;
+var b = <></>;
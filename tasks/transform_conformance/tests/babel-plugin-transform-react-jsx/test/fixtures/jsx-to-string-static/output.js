@@ -0,0 +1,2 @@
+var a = "<div class=\"card\" data-x=\"&quot;1&quot;\"><h1>Tom &amp; Jerry</h1><input disabled><br>a &lt; b</div>";
+var b = "";
@@ -0,0 +1,3 @@
+var a = <div key={k} id="x" />;
+var b = <div key={k} {...props} />;
+var c = <div {...props} key={k} />;
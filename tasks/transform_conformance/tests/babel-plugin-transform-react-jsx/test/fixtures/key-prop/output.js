@@ -0,0 +1,12 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+import { createElement as _createElement } from "react";
+var a = _jsx("div", {
+  id: "x"
+}, k);
+var b = _jsx("div", {
+  ...props
+}, k);
+var c = _createElement("div", {
+  ...props,
+  key: k
+});
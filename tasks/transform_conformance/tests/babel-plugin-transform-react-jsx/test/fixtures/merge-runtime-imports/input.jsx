@@ -0,0 +1,7 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+import { useState } from "react";
+import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+import * as runtime from "react/jsx-runtime";
+var a = _jsxs("div", { children: [useState(), runtime] });
+var b = <Foo />;
+var c = <div {...props} key="k" />;
@@ -0,0 +1,11 @@
+import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+import { useState, createElement as _createElement } from "react";
+import * as runtime from "react/jsx-runtime";
+var a = _jsxs("div", {
+  children: [useState(), runtime]
+});
+var b = _jsx(Foo, {});
+var c = _createElement("div", {
+  ...props,
+  key: "k"
+});
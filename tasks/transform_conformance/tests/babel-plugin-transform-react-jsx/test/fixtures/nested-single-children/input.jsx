@@ -0,0 +1,6 @@
+var a = <a><b><c /></b></a>;
+var b = <a>
+  <b>
+    <c />
+  </b>
+</a>;
@@ -0,0 +1,11 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+var a = _jsx("a", {
+  children: _jsx("b", {
+    children: _jsx("c", {})
+  })
+});
+var b = _jsx("a", {
+  children: _jsx("b", {
+    children: _jsx("c", {})
+  })
+});
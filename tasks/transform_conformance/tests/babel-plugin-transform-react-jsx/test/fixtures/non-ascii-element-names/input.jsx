@@ -0,0 +1,7 @@
+<x />;
+<X />;
+<ω />;
+<Ω />;
+<é />;
+<É />;
+<ñandú />;
@@ -0,0 +1,8 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+_jsx("x", {});
+_jsx(X, {});
+_jsx(ω, {});
+_jsx(Ω, {});
+_jsx(é, {});
+_jsx(É, {});
+_jsx(ñandú, {});
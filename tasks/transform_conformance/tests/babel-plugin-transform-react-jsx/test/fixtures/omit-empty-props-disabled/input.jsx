@@ -0,0 +1,5 @@
+var a = <div />;
+var b = <></>;
+var c = <div key="k" />;
+var d = <div className="a" />;
+var e = <div>text</div>;
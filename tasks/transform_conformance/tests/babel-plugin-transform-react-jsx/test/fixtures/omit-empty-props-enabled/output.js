@@ -0,0 +1,10 @@
+import { jsx as _jsx, Fragment as _Fragment } from "react/jsx-runtime";
+var a = _jsx("div");
+var b = _jsx(_Fragment);
+var c = _jsx("div", {}, "k");
+var d = _jsx("div", {
+  className: "a"
+});
+var e = _jsx("div", {
+  children: "text"
+});
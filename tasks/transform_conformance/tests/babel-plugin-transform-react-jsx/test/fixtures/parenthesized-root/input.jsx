@@ -0,0 +1,9 @@
+const x = (<div />);
+const y = ((<><span /></>));
+function render() {
+  return (
+    <div>
+      {(<span />)}
+    </div>
+  );
+}
@@ -0,0 +1,10 @@
+import { jsx as _jsx, Fragment as _Fragment } from "react/jsx-runtime";
+const x = _jsx("div", {});
+const y = _jsx(_Fragment, {
+  children: _jsx("span", {})
+});
+function render() {
+  return _jsx("div", {
+    children: _jsx("span", {})
+  });
+}
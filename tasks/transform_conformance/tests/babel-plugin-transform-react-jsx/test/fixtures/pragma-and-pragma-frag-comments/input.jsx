@@ -0,0 +1,4 @@
+/** @jsx h */
+/** @jsxFrag Fragment */
+var a = <div className="a">text</div>;
+var b = <><span /></>;
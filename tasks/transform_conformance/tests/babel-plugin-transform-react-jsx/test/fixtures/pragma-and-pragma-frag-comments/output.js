@@ -0,0 +1,6 @@
+/** @jsx h */
+/** @jsxFrag Fragment */
+var a = h("div", {
+  className: "a"
+}, "text");
+var b = h(Fragment, null, h("span", null));
@@ -0,0 +1,4 @@
+/** @jsx h the hyperscript factory */
+/** @jsxFrag Fragment from preact */
+var a = <div />;
+var b = <><span /></>;
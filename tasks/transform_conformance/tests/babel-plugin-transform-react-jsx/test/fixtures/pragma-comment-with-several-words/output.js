@@ -0,0 +1,4 @@
+/** @jsx h the hyperscript factory */
+/** @jsxFrag Fragment from preact */
+var a = h("div", null);
+var b = h(Fragment, null, h("span", null));
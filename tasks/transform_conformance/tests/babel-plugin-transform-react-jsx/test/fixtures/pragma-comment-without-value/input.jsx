@@ -0,0 +1,4 @@
+/** @jsx */
+/** @jsxFrag */
+var a = <div />;
+var b = <><span /></>;
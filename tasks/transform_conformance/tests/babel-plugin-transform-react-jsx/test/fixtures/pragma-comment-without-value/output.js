@@ -0,0 +1,4 @@
+/** @jsx */
+/** @jsxFrag */
+var a = React.createElement("div", null);
+var b = React.createElement(React.Fragment, null, React.createElement("span", null));
@@ -0,0 +1,7 @@
+/**
+ * @jsxRuntime classic
+ * @jsx h
+ * @jsxFrag Fragment
+ */
+var a = <div className="a">text</div>;
+var b = <><span /></>;
@@ -0,0 +1,9 @@
+/**
+ * @jsxRuntime classic
+ * @jsx h
+ * @jsxFrag Fragment
+ */
+var a = h("div", {
+  className: "a"
+}, "text");
+var b = h(Fragment, null, h("span", null));
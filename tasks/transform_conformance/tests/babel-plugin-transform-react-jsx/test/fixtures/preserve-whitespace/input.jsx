@@ -0,0 +1,7 @@
+var a = <div>
+  a  b
+</div>;
+var b = <div>
+  <span />
+  <span> x &amp; y </span>
+</div>;
@@ -0,0 +1,9 @@
+import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+var a = _jsx("div", {
+  children: "\n  a  b\n"
+});
+var b = _jsxs("div", {
+  children: ["\n  ", _jsx("span", {}), "\n  ", _jsx("span", {
+    children: " x & y "
+  }), "\n"]
+});
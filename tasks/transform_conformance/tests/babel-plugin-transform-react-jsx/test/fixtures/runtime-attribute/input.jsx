@@ -0,0 +1,4 @@
+var a = <div data-jsx-runtime="classic" />;
+var b = <Foo data-jsx-runtime="classic" bar="1">child</Foo>;
+var c = <div data-jsx-runtime="automatic" className="a" />;
+var d = <div className="b"><span data-jsx-runtime="classic" key="k" /></div>;
@@ -0,0 +1,15 @@
+import { createElement as _createElement } from "react";
+import { jsx as _jsx } from "react/jsx-runtime";
+var a = _createElement("div", null);
+var b = _createElement(Foo, {
+  bar: "1"
+}, "child");
+var c = _jsx("div", {
+  className: "a"
+});
+var d = _jsx("div", {
+  className: "b",
+  children: _createElement("span", {
+    key: "k"
+  })
+});
@@ -0,0 +1,5 @@
+var a = <br />;
+var b = <br></br>;
+var c = <Foo bar="baz" />;
+var d = <Foo bar="baz"></Foo>;
+var e = <></>;
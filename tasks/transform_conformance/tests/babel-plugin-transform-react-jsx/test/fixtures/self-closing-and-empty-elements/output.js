@@ -0,0 +1,10 @@
+import { jsx as _jsx, Fragment as _Fragment } from "react/jsx-runtime";
+var a = _jsx("br", {});
+var b = _jsx("br", {});
+var c = _jsx(Foo, {
+  bar: "baz"
+});
+var d = _jsx(Foo, {
+  bar: "baz"
+});
+var e = _jsx(_Fragment, {});
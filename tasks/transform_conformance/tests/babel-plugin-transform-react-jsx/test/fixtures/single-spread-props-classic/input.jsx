@@ -0,0 +1,2 @@
+var a = <div {...props} />;
+var b = <div {...props}>child</div>;
@@ -0,0 +1,2 @@
+var a = React.createElement("div", props);
+var b = React.createElement("div", props, "child");
@@ -0,0 +1,8 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+var a = _jsx("div", {
+  ...props
+});
+var b = _jsx("div", {
+  ...props,
+  children: "child"
+});
@@ -0,0 +1,6 @@
+<p>Hello {name}!</p>;
+<p>
+  Hello {name} and {other}
+  !
+</p>;
+<p>{a} {b}</p>;
@@ -0,0 +1,10 @@
+import { jsxs as _jsxs } from "react/jsx-runtime";
+_jsxs("p", {
+  children: ["Hello ", name, "!"]
+});
+_jsxs("p", {
+  children: ["Hello ", name, " and ", other, "!"]
+});
+_jsxs("p", {
+  children: [a, " ", b]
+});
@@ -0,0 +1,6 @@
+var x = <div className="a">
+  <Child />
+</div>;
+var y = <>
+  <span />
+</>;
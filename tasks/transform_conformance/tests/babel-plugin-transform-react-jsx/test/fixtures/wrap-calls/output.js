@@ -0,0 +1,8 @@
+import { jsx as _jsx, Fragment as _Fragment } from "react/jsx-runtime";
+var x = __track(_jsx("div", {
+  className: "a",
+  children: __track(_jsx(Child, {}))
+}));
+var y = __track(_jsx(_Fragment, {
+  children: __track(_jsx("span", {}))
+}));